@@ -4,24 +4,34 @@ use std::{
 };
 
 #[cfg(target_os = "linux")]
-use linux::{Process, ProcessIterator, MemoryRegionIterator};
-#[cfg(target_os = "windows")]
-use windows::Process;
+use linux::{raise_fd_limit, Process, ProcessIterator, MemoryRegionIterator};
 
-use context::{ScannerContext, ProcessDTO, SelectProcessParams, ScanParam, ScanResultParams};
+use context::{ScannerContext, ProcessDTO, SelectProcessParams, ScanParam, ScanResultParams, PatternScanParams, PointerScanParams, WriteMemoryParams, FreezeValueParams, UnfreezeValueParams};
 
 use serde_json;
 use jsonrpsee::core::server::RpcModule;
 
+// `windows::Process` doesn't implement `core::Process` (nor does its
+// `MemoryRegionIterator` implement `core::MemoryRegionIterator`), so `ScannerContext<P>`, which every handler below is
+// built on, can't be instantiated for it yet; gate the whole RPC server to
+// the platform that actually has a `core::Process` impl rather than half-wire
+// a Windows build that still can't select a process.
+#[cfg(target_os = "linux")]
 async fn cli() {
     let mut module: RpcModule<Mutex<ScannerContext<Process>>> = RpcModule::new(Mutex::new(ScannerContext::default()));
 
     // {"jsonrpc": "2.0", "method": "list_processes", "id": 1}
     module
         .register_method("list_processes", |_, _| {
-            ProcessIterator::new()
-                .map(|p| ProcessDTO::new(&p))
-                .collect::<Vec<ProcessDTO>>()
+            #[cfg(target_os = "linux")]
+            let _ = raise_fd_limit();
+
+            #[cfg(target_os = "linux")]
+            let processes = ProcessIterator::new().scannable_only();
+            #[cfg(not(target_os = "linux"))]
+            let processes = ProcessIterator::new();
+
+            processes.map(|p| ProcessDTO::new(&p)).collect::<Vec<ProcessDTO>>()
         })
         .unwrap();
 
@@ -55,6 +65,32 @@ async fn cli() {
         })
         .unwrap();
 
+    // {"jsonrpc": "2.0", "method": "list_modules", "id": 1}
+    module
+        .register_blocking_method("list_modules", |_, context| {
+            let modules = context.lock().unwrap().list_modules::<MemoryRegionIterator>();
+            serde_json::to_value(modules).unwrap()
+        })
+        .unwrap();
+
+    // {"jsonrpc": "2.0", "method": "pattern_scan", "params": { "pattern": "48 8B 3D ? ? ? ? 44 89 E3", "operations": [{ "op": "rip" }] }, "id": 1}
+    module
+        .register_blocking_method("pattern_scan", |params, context| {
+            let parsed: PatternScanParams = params.parse().unwrap();
+            let addresses = context.lock().unwrap().pattern_scan::<MemoryRegionIterator>(parsed);
+            serde_json::to_value(addresses).unwrap()
+        })
+        .unwrap();
+
+    // {"jsonrpc": "2.0", "method": "pointer_scan", "params": { "target": 93824992233472, "max_depth": 5, "max_offset": 4096 }, "id": 1}
+    module
+        .register_blocking_method("pointer_scan", |params, context| {
+            let parsed: PointerScanParams = params.parse().unwrap();
+            let chains = context.lock().unwrap().pointer_scan::<MemoryRegionIterator>(parsed);
+            serde_json::to_value(chains).unwrap()
+        })
+        .unwrap();
+
     // {"jsonrpc": "2.0", "method": "scan_result", "params": { "offset": 0, "limit": 2 }, "id": 1}
     module
         .register_blocking_method("scan_result", |params, context| {
@@ -63,6 +99,41 @@ async fn cli() {
         })
         .unwrap();
 
+    // {"jsonrpc": "2.0", "method": "write_memory", "params": { "address": 93824992233472, "value": { "type": "dword", "value": 100 } }, "id": 1}
+    module
+        .register_blocking_method("write_memory", |params, context| {
+            let parsed: WriteMemoryParams = params.parse().unwrap();
+            context.lock().unwrap().write_memory(parsed);
+            serde_json::Value::Null
+        })
+        .unwrap();
+
+    // {"jsonrpc": "2.0", "method": "freeze_value", "params": { "address": 93824992233472, "value": { "type": "dword", "value": 100 } }, "id": 1}
+    module
+        .register_blocking_method("freeze_value", |params, context| {
+            let parsed: FreezeValueParams = params.parse().unwrap();
+            context.lock().unwrap().freeze_value(parsed);
+            serde_json::Value::Null
+        })
+        .unwrap();
+
+    // {"jsonrpc": "2.0", "method": "unfreeze_value", "params": { "address": 93824992233472 }, "id": 1}
+    module
+        .register_blocking_method("unfreeze_value", |params, context| {
+            let parsed: UnfreezeValueParams = params.parse().unwrap();
+            context.lock().unwrap().unfreeze_value(parsed);
+            serde_json::Value::Null
+        })
+        .unwrap();
+
+    // {"jsonrpc": "2.0", "method": "list_frozen", "id": 1}
+    module
+        .register_blocking_method("list_frozen", |_, context| {
+            let frozen = context.lock().unwrap().list_frozen();
+            serde_json::to_value(frozen).unwrap()
+        })
+        .unwrap();
+
     for line in io::stdin().lines() {
         let (response, _) = module
             .raw_json_request(line.unwrap().as_str(), 1)
@@ -72,6 +143,11 @@ async fn cli() {
     }
 }
 
+#[cfg(not(target_os = "linux"))]
+async fn cli() {
+    unimplemented!("the scanner RPC server only supports Linux until windows::Process implements core::Process");
+}
+
 fn main() {
     let rt = tokio::runtime::Builder::new_current_thread()
         .build()
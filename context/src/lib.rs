@@ -1,11 +1,14 @@
 use std::{
     process::{Child, Command, Stdio},
     sync::Arc,
+    time::Duration,
 };
 
 use core::{PID, Process, MemoryRegionIterator};
 
-use scanner::Scanner;
+use modules::ModuleMap;
+use pointer_scanner::PointerScanner;
+use scanner::{Comparator, Freezer, Scannable, Scanner};
 use serde::{Deserialize, Serialize};
 use serde_json::Number;
 
@@ -20,9 +23,15 @@ pub enum SelectProcessParams {
 pub struct ProcessDTO {
     pid: PID,
     name: String,
+    command_line: Vec<String>,
+    exe_path: Option<String>,
+    parent_pid: Option<PID>,
+    is_64_bit: bool,
+    status: String,
+    uid: Option<u32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ScanValueType {
     Byte,
@@ -33,6 +42,104 @@ pub enum ScanValueType {
     Double,
 }
 
+/// A numeric type a scan can run over: bridges a `serde_json::Number` coming
+/// in over the RPC boundary and a `scanner::Scanner<T>` monomorphization
+/// going out. Implemented for every concrete type `with_scan_type!` can
+/// select, so `ScannerContext` never repeats per-type scan/compare/result
+/// logic by hand.
+trait ScanNumber: Scannable + PartialOrd + std::ops::Sub<Output = Self> {
+    fn from_number(value: &Number) -> Self;
+    /// `None` for a float that reads back as NaN/Inf, which has no JSON
+    /// representation; `scan_result` drops such addresses instead of
+    /// reporting them.
+    fn to_number(self) -> Option<Number>;
+}
+
+macro_rules! impl_scan_number_int {
+    ($($t:ty => $as:ident),* $(,)?) => {
+        $(impl ScanNumber for $t {
+            fn from_number(value: &Number) -> Self {
+                <$t>::try_from(value.$as().expect("value doesn't fit the scan's integer type")).unwrap()
+            }
+            fn to_number(self) -> Option<Number> {
+                Some(Number::from(self))
+            }
+        })*
+    };
+}
+
+impl_scan_number_int!(i8 => as_i64, i16 => as_i64, i32 => as_i64, i64 => as_i64);
+impl_scan_number_int!(u8 => as_u64, u16 => as_u64, u32 => as_u64, u64 => as_u64);
+
+impl ScanNumber for f32 {
+    fn from_number(value: &Number) -> Self {
+        value.as_f64().expect("value isn't a number") as f32
+    }
+    fn to_number(self) -> Option<Number> {
+        Number::from_f64(self as f64)
+    }
+}
+
+impl ScanNumber for f64 {
+    fn from_number(value: &Number) -> Self {
+        value.as_f64().expect("value isn't a number")
+    }
+    fn to_number(self) -> Option<Number> {
+        Number::from_f64(self)
+    }
+}
+
+/// Binds `$T` to the concrete Rust type selected by `$value_type`/`$signed`
+/// and runs `$body` against it, so a single call site covers every
+/// `ScanValueType` instead of one hand-written match arm per type. `signed`
+/// is meaningless for `Float`/`Double` and ignored for them.
+macro_rules! with_scan_type {
+    ($value_type:expr, $signed:expr, |$T:ident| $body:block) => {
+        match ($value_type, $signed) {
+            (ScanValueType::Byte, true) => {
+                type $T = i8;
+                $body
+            }
+            (ScanValueType::Byte, false) => {
+                type $T = u8;
+                $body
+            }
+            (ScanValueType::WORD, true) => {
+                type $T = i16;
+                $body
+            }
+            (ScanValueType::WORD, false) => {
+                type $T = u16;
+                $body
+            }
+            (ScanValueType::DWORD, true) => {
+                type $T = i32;
+                $body
+            }
+            (ScanValueType::DWORD, false) => {
+                type $T = u32;
+                $body
+            }
+            (ScanValueType::QWORD, true) => {
+                type $T = i64;
+                $body
+            }
+            (ScanValueType::QWORD, false) => {
+                type $T = u64;
+                $body
+            }
+            (ScanValueType::Float, _) => {
+                type $T = f32;
+                $body
+            }
+            (ScanValueType::Double, _) => {
+                type $T = f64;
+                $body
+            }
+        }
+    };
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ScanValue {
     #[serde(rename = "type")]
@@ -45,16 +152,89 @@ pub struct ScanCount {
     count: usize,
 }
 
+#[derive(Deserialize)]
+pub struct WriteMemoryParams {
+    pub address: usize,
+    pub value: ScanValue,
+}
+
+#[derive(Deserialize)]
+pub struct FreezeValueParams {
+    pub address: usize,
+    pub value: ScanValue,
+    #[serde(default = "default_freeze_interval_ms")]
+    pub interval_ms: u64,
+}
+
+fn default_freeze_interval_ms() -> u64 {
+    100
+}
+
+#[derive(Deserialize)]
+pub struct UnfreezeValueParams {
+    pub address: usize,
+}
+
+/// An address reported by the `list_frozen` RPC.
+#[derive(Serialize, Clone)]
+pub struct FrozenEntry {
+    address: usize,
+}
+
+/// A `scanner::Freezer` keeping one address pinned to a value, owned by
+/// `ScannerContext` so `list_frozen`/`unfreeze_value` can look it up by
+/// address; the writer thread itself is stopped and joined by `Freezer`'s
+/// `Drop` impl whenever its entry is removed from `freezes`.
+struct Freeze {
+    address: usize,
+    freezer: Freezer,
+}
+
 #[derive(Deserialize)]
 pub struct ScanResultParams {
     pub offset: usize,
     pub limit: usize,
+    /// Report addresses as `module+offset` instead of raw absolute addresses,
+    /// using the module map built by `refresh_modules`.
+    #[serde(default)]
+    pub relative: bool,
 }
 
 #[derive(Serialize, Clone)]
 pub struct ScanResultEntry {
     address: usize,
     value: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    module: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    module_offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    section: Option<String>,
+}
+
+/// Lowercase hex, e.g. for a module's GNU build-id. No `hex` crate dependency
+/// exists elsewhere in the workspace, so this stays a small hand-rolled loop.
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn into_comparator<T: ScanNumber>(comparator: ScanComparator) -> Comparator<T> {
+    match comparator {
+        ScanComparator::Increased => Comparator::Increased,
+        ScanComparator::Decreased => Comparator::Decreased,
+        ScanComparator::Changed => Comparator::Changed,
+        ScanComparator::Unchanged => Comparator::Unchanged,
+        ScanComparator::IncreasedBy { value } => Comparator::IncreasedBy(T::from_number(&value)),
+        ScanComparator::DecreasedBy { value } => Comparator::DecreasedBy(T::from_number(&value)),
+        ScanComparator::Equal { value } => Comparator::Equal(T::from_number(&value)),
+        ScanComparator::GreaterThan { value } => Comparator::GreaterThan(T::from_number(&value)),
+        ScanComparator::LessThan { value } => Comparator::LessThan(T::from_number(&value)),
+        ScanComparator::Between { low, high } => {
+            Comparator::Between(T::from_number(&low), T::from_number(&high))
+        }
+    }
 }
 
 impl ProcessDTO {
@@ -62,37 +242,297 @@ impl ProcessDTO {
         Self {
             pid: p.pid(),
             name: p.name(),
+            command_line: p.command_line(),
+            exe_path: p.exe_path(),
+            parent_pid: p.parent_pid(),
+            is_64_bit: p.is_64_bit(),
+            status: p.status().to_string(),
+            uid: p.uid(),
         }
     }
 }
 
+/// The "unknown initial value" comparators from the `scanner` crate, as they
+/// arrive over the RPC boundary. `IncreasedBy`/`DecreasedBy` carry the exact
+/// delta as JSON numbers rather than pre-typed values, since the concrete
+/// type is only known once `value_type`/`signed` are consulted.
 #[derive(Debug, Deserialize)]
-pub struct ScanParam {
-    pub value: ScanValue,
+#[serde(rename_all = "lowercase")]
+pub enum ScanComparator {
+    Increased,
+    Decreased,
+    Changed,
+    Unchanged,
+    IncreasedBy { value: Number },
+    DecreasedBy { value: Number },
+    Equal { value: Number },
+    GreaterThan { value: Number },
+    LessThan { value: Number },
+    Between { low: Number, high: Number },
+}
+
+/// A scan pass is either an exact-value match, the first "unknown initial
+/// value" pass (just records every candidate's current value), or a
+/// comparator pass filtering against the previous pass's recorded values.
+/// Declaration order matters: `Comparator` is tried before `Unknown` so an
+/// object carrying a `comparator` field isn't swallowed by `Unknown`'s looser
+/// shape.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ScanParam {
+    Exact {
+        value: ScanValue,
+        /// Restrict the scan to this module's address range (from the map
+        /// built by `refresh_modules`) instead of the whole address space.
+        #[serde(default)]
+        module: Option<String>,
+    },
+    Comparator {
+        #[serde(rename = "type")]
+        type_: ScanValueType,
+        comparator: ScanComparator,
+        /// No value to infer sign from (unlike `Exact`'s JSON number), so
+        /// ordered comparators on integer data need this spelled out
+        /// explicitly; defaults to unsigned. Only read when this param starts
+        /// a scan via `new_scan`: `next_scan` keeps the type and signedness
+        /// that `new_scan` chose.
+        #[serde(default)]
+        signed: bool,
+        #[serde(default)]
+        module: Option<String>,
+    },
+    Unknown {
+        #[serde(rename = "type")]
+        type_: ScanValueType,
+        #[serde(default)]
+        signed: bool,
+        #[serde(default)]
+        module: Option<String>,
+    },
+}
+
+/// A loaded module as reported by the `list_modules` RPC.
+#[derive(Serialize, Clone)]
+pub struct ModuleDTO {
+    name: String,
+    base_address: usize,
+    size: usize,
+    path: String,
+    /// GNU build-id (ELF only), hex-encoded, so a front-end can tell two
+    /// same-named modules at different addresses/versions apart.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_id: Option<String>,
+}
+
+/// Params for the `pointer_scan` RPC: find pointer chains from a module's
+/// static base to `target` that survive ASLR across relaunches.
+#[derive(Debug, Deserialize)]
+pub struct PointerScanParams {
+    pub target: usize,
+    #[serde(default = "default_pointer_scan_max_depth")]
+    pub max_depth: usize,
+    #[serde(default = "default_pointer_scan_max_offset")]
+    pub max_offset: usize,
+}
+
+fn default_pointer_scan_max_depth() -> usize {
+    5
+}
+
+fn default_pointer_scan_max_offset() -> usize {
+    0x1000
+}
+
+/// A pointer chain as reported by the `pointer_scan` RPC.
+#[derive(Serialize, Clone)]
+pub struct PointerChainDTO {
+    module_name: String,
+    base_offset: usize,
+    offsets: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PatternScanParams {
+    pub pattern: String,
+    /// Restrict the scan to this module's address range (from the map built
+    /// by `refresh_modules`) instead of the whole address space.
+    #[serde(default)]
+    pub module: Option<String>,
+    #[serde(default)]
+    pub operations: Vec<PatternOperation>,
+}
+
+/// A post-match operation applied, in sequence, to a `pattern_scan` hit.
+/// The running result starts out as the match's absolute address.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatternOperation {
+    /// Reads a 4-byte little-endian displacement at `address + offset` and
+    /// resolves the RIP-relative target as `address + offset + length + disp`,
+    /// i.e. the usual `lea reg, [rip + disp]`-style addressing.
+    Rip {
+        #[serde(default = "default_rip_offset")]
+        offset: usize,
+        #[serde(default = "default_rip_length")]
+        length: usize,
+    },
+    /// Replaces the running result with the little-endian integer formed by
+    /// `matched_bytes[start..end]`.
+    Slice { start: usize, end: usize },
+    /// Adds a constant to the running result.
+    Add { value: i64 },
+}
+
+fn default_rip_offset() -> usize {
+    3
+}
+
+fn default_rip_length() -> usize {
+    7
+}
+
+/// Whether a `ScanValue`'s type/value pair should be read back as the signed
+/// or unsigned half of its `with_scan_type!` pairing. Floats carry no
+/// separate sign bit, so `signed` is meaningless (and ignored) for them.
+fn scan_value_signed(value: &ScanValue) -> bool {
+    !matches!(value.type_, ScanValueType::Float | ScanValueType::Double) && value.value.is_i64()
+}
+
+/// Runs `operations` against one `pattern_scan` hit. Returns `None` (instead
+/// of panicking the blocking RPC handler) if a `Rip` displacement can't be
+/// read or a `Slice` range falls outside `matched_bytes`, both of which are
+/// driven by caller-supplied params; the match is then dropped from the
+/// result rather than failing the whole scan.
+fn apply_pattern_operations<P: Process>(
+    process: &P,
+    address: usize,
+    matched_bytes: &[u8],
+    operations: &[PatternOperation],
+) -> Option<usize> {
+    let mut result = address;
+
+    for operation in operations {
+        match operation {
+            PatternOperation::Rip { offset, length } => {
+                let mut disp_bytes = [0u8; 4];
+                process.read_memory_slice(address + offset, &mut disp_bytes).ok()?;
+                let disp = i32::from_le_bytes(disp_bytes);
+                result = (address as i64 + *offset as i64 + *length as i64 + disp as i64) as usize;
+            }
+            PatternOperation::Slice { start, end } => {
+                result = matched_bytes
+                    .get(*start..*end)?
+                    .iter()
+                    .rev()
+                    .fold(0usize, |acc, &byte| (acc << 8) | byte as usize);
+            }
+            PatternOperation::Add { value } => {
+                result = (result as i64 + value) as usize;
+            }
+        }
+    }
+
+    Some(result)
 }
 
 pub struct ScannerContext<P>
 where P: Process {
+    process: Option<Arc<P>>,
     scanner: Option<Scanner<P>>,
     value_type: Option<ScanValueType>,
     signed: Option<bool>,
     child: Option<Child>,
+    modules: Option<ModuleMap>,
+    freezes: Vec<Freeze>,
 }
 
 impl<P> Default for ScannerContext<P>
 where P: Process {
     fn default() -> Self {
-        Self { scanner: None, value_type: None, signed: None, child: None }
+        Self {
+            process: None,
+            scanner: None,
+            value_type: None,
+            signed: None,
+            child: None,
+            modules: None,
+            freezes: Vec::new(),
+        }
     }
 }
 
 impl<P> ScannerContext<P>
 where P: Process {
     pub fn select_process(&mut self, pid: PID) -> ProcessDTO {
+        // Dropping each `Freeze` drops its `Freezer`, which stops and joins
+        // the writer thread.
+        self.freezes.clear();
+
         let process = Arc::<P>::new(core::Process::new(pid));
         let scanner = Scanner::new(process.clone());
         self.scanner = Some(scanner);
-        ProcessDTO::new(process.as_ref())
+        self.modules = None;
+        let dto = ProcessDTO::new(process.as_ref());
+        self.process = Some(process);
+        dto
+    }
+
+    /// (Re)build the module map for the selected process by walking its
+    /// memory regions and grouping consecutive mappings that share a backing
+    /// file path into one module, so scan results can be reported and
+    /// re-resolved as `module+offset` instead of raw, ASLR-sensitive addresses.
+    pub fn refresh_modules<M: for<'a> MemoryRegionIterator<'a, P>>(&mut self) {
+        let process = self.process.as_ref().expect("no process selected");
+
+        let mut by_path: Vec<(String, usize, usize)> = Vec::new();
+        for region in M::new(process.as_ref(), 0, usize::MAX) {
+            let Some(path) = region.path else { continue };
+            match by_path.iter_mut().find(|(p, _, end)| *p == path && *end == region.range.start) {
+                Some((_, _, end)) => *end = region.range.end,
+                None => by_path.push((path, region.range.start, region.range.end)),
+            }
+        }
+
+        self.modules = Some(ModuleMap::from_regions(
+            by_path.into_iter().map(|(path, start, end)| (path, start, end - start)),
+        ));
+    }
+
+    /// Resolve an optional `module` scan param into a `[start, start+limit)`
+    /// address range understood by `MemoryRegionIterator`, or the whole
+    /// address space when no module was given.
+    fn module_range(&self, module: &Option<String>) -> (usize, usize) {
+        match module {
+            Some(name) => {
+                let found = self
+                    .modules
+                    .as_ref()
+                    .and_then(|m| m.find(name))
+                    .unwrap_or_else(|| panic!("module {:?} not found; call refresh_modules first", name));
+                (found.base, found.size)
+            }
+            None => (0, usize::MAX),
+        }
+    }
+
+    /// List every module known to the module map, rebuilding it first so the
+    /// result reflects the process's current memory layout.
+    pub fn list_modules<M: for<'a> MemoryRegionIterator<'a, P>>(&mut self) -> Vec<ModuleDTO> {
+        self.refresh_modules::<M>();
+
+        self.modules
+            .as_ref()
+            .unwrap()
+            .modules()
+            .iter()
+            .map(|module| ModuleDTO {
+                name: module.name.clone(),
+                base_address: module.base,
+                size: module.size,
+                path: module.path.clone(),
+                build_id: module.build_id.as_ref().map(|id| hex_encode(id)),
+            })
+            .collect()
     }
 
     pub fn open_process(&mut self, path: &str) -> ProcessDTO {
@@ -107,31 +547,43 @@ where P: Process {
     }
 
     pub fn new_scan<'a, M>(&'a mut self, scan_param: ScanParam) -> ScanCount
-    where M: MemoryRegionIterator<P> {
-        let scan_value = scan_param.value;
+    where M: MemoryRegionIterator<'a, P> {
+        let module = match &scan_param {
+            ScanParam::Exact { module, .. }
+            | ScanParam::Comparator { module, .. }
+            | ScanParam::Unknown { module, .. } => module,
+        };
+        let (start, limit) = self.module_range(module);
 
         let scanner = self.scanner.as_mut().unwrap();
 
-        match scan_value {
-            ScanValue {
-                type_: ScanValueType::DWORD,
-                value,
-            } => {
-                if value.is_i64() {
-                    scanner.new_scan::<i32, _, M>(|&x| {
-                        x == i32::try_from(value.as_i64().unwrap()).unwrap()
-                    });
-                    self.signed = Some(true);
-                } else {
-                    scanner.new_scan::<u32, _, M>(|&x| {
-                        x == u32::try_from(value.as_u64().unwrap()).unwrap()
-                    });
-                    self.signed = Some(false);
-                }
+        let (value_type, signed) = match scan_param {
+            ScanParam::Exact { value: ScanValue { type_, value }, .. } => {
+                // Floats carry no separate sign bit; `signed` is only
+                // consulted by `with_scan_type!` for the integer types.
+                let signed = !matches!(type_, ScanValueType::Float | ScanValueType::Double)
+                    && value.is_i64();
+
+                with_scan_type!(type_, signed, |T| {
+                    scanner
+                        .new_scan::<T, _, M>(|&x| x.scan_eq(&T::from_number(&value)), start, limit)
+                        .expect("process disappeared during scan");
+                });
+
+                (type_, signed)
             }
-            _ => panic!("{:#?} not supported", scan_value.type_),
-        }
-        self.value_type = Some(scan_value.type_);
+            ScanParam::Unknown { type_, signed, .. } | ScanParam::Comparator { type_, signed, .. } => {
+                with_scan_type!(type_, signed, |T| {
+                    scanner
+                        .new_scan_unknown::<T, M>(start, limit)
+                        .expect("process disappeared during scan");
+                });
+
+                (type_, signed)
+            }
+        };
+        self.value_type = Some(value_type);
+        self.signed = Some(signed);
 
         ScanCount {
             count: scanner.get_addresses().len(),
@@ -139,52 +591,178 @@ where P: Process {
     }
 
     pub fn next_scan(&mut self, scan_param: ScanParam) -> ScanCount {
-        let scan_value = scan_param.value;
-
         let scanner = self.scanner.as_mut().unwrap();
+        let value_type = self.value_type.expect("no scan in progress; call new_scan first");
+        let signed = self.signed.unwrap();
 
-        match scan_value {
-            ScanValue {
-                type_: ScanValueType::DWORD,
-                value,
-            } => {
-                if value.is_i64() {
-                    scanner.next_scan::<i32, _>(|&x| {
-                        x == i32::try_from(value.as_i64().unwrap()).unwrap()
-                    });
-                    self.signed = Some(true);
-                } else {
-                    scanner.next_scan::<u32, _>(|&x| {
-                        x == u32::try_from(value.as_u64().unwrap()).unwrap()
-                    });
-                    self.signed = Some(false);
-                }
+        match scan_param {
+            ScanParam::Exact { value: ScanValue { value, .. }, .. } => {
+                with_scan_type!(value_type, signed, |T| {
+                    scanner
+                        .next_scan::<T, _>(|&x| x.scan_eq(&T::from_number(&value)))
+                        .expect("process disappeared during scan");
+                });
+            }
+            ScanParam::Comparator { comparator, .. } => {
+                with_scan_type!(value_type, signed, |T| {
+                    scanner
+                        .next_scan_compare::<T>(into_comparator::<T>(comparator))
+                        .expect("process disappeared during scan");
+                });
             }
-            _ => panic!("{:#?} not supported", scan_value.type_),
+            ScanParam::Unknown { .. } => panic!("an unknown-initial-value scan must start with new_scan"),
         }
-        self.value_type = Some(scan_value.type_);
 
         ScanCount {
             count: scanner.get_addresses().len(),
         }
     }
 
+    /// Array-of-bytes signature scan, the offset-finder counterpart to
+    /// `new_scan`/`next_scan`: parses an IDA-style pattern, scans (optionally
+    /// restricted to one module's range), and resolves each match through
+    /// `operations` instead of returning the raw match address.
+    pub fn pattern_scan<M: for<'a> MemoryRegionIterator<'a, P>>(
+        &mut self,
+        params: PatternScanParams,
+    ) -> Vec<usize> {
+        let process = self.process.clone().expect("no process selected");
+        let pattern = scanner::parse_pattern(&params.pattern);
+        let (start, limit) = self.module_range(&params.module);
+
+        let scanner = self.scanner.as_mut().expect("no process selected");
+        scanner
+            .pattern_scan::<M>(&pattern, start, limit)
+            .expect("process disappeared during scan")
+            .into_iter()
+            .filter_map(|(address, matched_bytes)| {
+                apply_pattern_operations(process.as_ref(), address, &matched_bytes, &params.operations)
+            })
+            .collect()
+    }
+
+    /// Find pointer chains from a module's static base to `params.target`,
+    /// rebuilding the module map first so chain roots reflect the process's
+    /// current layout.
+    pub fn pointer_scan<M: for<'a> MemoryRegionIterator<'a, P>>(&mut self, params: PointerScanParams) -> Vec<PointerChainDTO> {
+        self.refresh_modules::<M>();
+
+        let process = self.process.clone().expect("no process selected");
+        let modules = self.modules.as_ref().unwrap();
+
+        PointerScanner::new(process)
+            .find_chains::<M>(modules, params.target, params.max_depth, params.max_offset)
+            .expect("process disappeared during scan")
+            .into_iter()
+            .map(|chain| PointerChainDTO {
+                module_name: chain.module_name,
+                base_offset: chain.base_offset,
+                offsets: chain.offsets,
+            })
+            .collect()
+    }
+
     pub fn scan_result(&self, scan_result_params: ScanResultParams) -> Vec<ScanResultEntry> {
-        let ScanResultParams { offset, limit } = scan_result_params;
+        let ScanResultParams { offset, limit, relative } = scan_result_params;
+        let value_type = self.value_type.expect("no scan in progress");
+        let signed = self.signed.unwrap();
 
-        match (self.value_type.as_ref().unwrap(), self.signed.unwrap()) {
-            (ScanValueType::DWORD, true) => self
+        let mut entries: Vec<(usize, Number)> = Vec::new();
+        with_scan_type!(value_type, signed, |T| {
+            entries = self
                 .scanner
                 .as_ref()
                 .unwrap()
-                .scan_result::<i32>(offset, limit)
+                .scan_result::<T>(offset, limit)
                 .into_iter()
-                .map(|(address, value)| ScanResultEntry {
-                    address,
-                    value: Number::from(value),
-                })
-                .collect::<Vec<_>>(),
-            x => panic!("({:#?}, {:#?}) not supported", x.0, x.1),
+                .filter_map(|(address, value)| Some((address, value.to_number()?)))
+                .collect();
+        });
+
+        entries
+            .into_iter()
+            .map(|(address, value)| {
+                let (module, module_offset, symbol, section) = if relative {
+                    match self.modules.as_ref().and_then(|m| m.resolve(address)) {
+                        Some((name, offset)) => (
+                            Some(name.to_string()),
+                            Some(offset),
+                            self.modules.as_ref().and_then(|m| m.symbol_at(address)).map(str::to_string),
+                            self.modules.as_ref().and_then(|m| m.section_at(address)).map(str::to_string),
+                        ),
+                        None => (None, None, None, None),
+                    }
+                } else {
+                    (None, None, None, None)
+                };
+
+                ScanResultEntry { address, value, module, module_offset, symbol, section }
+            })
+            .collect()
+    }
+
+    /// Resolve a previously saved `module+offset` pair back into an absolute
+    /// address in the currently selected (possibly relaunched) process.
+    pub fn resolve_module_address(&self, module_name: &str, offset: usize) -> Option<usize> {
+        self.modules.as_ref()?.unresolve(module_name, offset)
+    }
+
+    /// Write a value found by scanning back into the process, e.g. to freeze
+    /// or edit it. Together with `freeze_value`/`unfreeze_value` below, this
+    /// is the read-modify-write loop a memory editor needs: a one-shot poke
+    /// plus a background thread that keeps re-asserting a value against the
+    /// target overwriting it.
+    pub fn write_memory(&self, params: WriteMemoryParams) {
+        let scanner = self.scanner.as_ref().expect("no process selected");
+        let WriteMemoryParams { address, value } = params;
+        let signed = scan_value_signed(&value);
+
+        with_scan_type!(value.type_, signed, |T| {
+            scanner
+                .write_value::<T>(address, T::from_number(&value.value))
+                .expect("failed to write memory");
+        });
+    }
+
+    /// Start repeatedly re-writing `params.value` to `params.address` every
+    /// `params.interval_ms` via a `Freezer`, so the target can't make it
+    /// stick (e.g. freezing HP). Re-freezing an already-frozen address stops
+    /// the old writer first so only one `Freezer` ever owns an address.
+    pub fn freeze_value(&mut self, params: FreezeValueParams)
+    where
+        P: 'static,
+    {
+        let process = self.process.clone().expect("no process selected");
+        let FreezeValueParams { address, value, interval_ms } = params;
+        let signed = scan_value_signed(&value);
+        let interval = Duration::from_millis(interval_ms);
+
+        self.stop_freeze(address);
+
+        with_scan_type!(value.type_, signed, |T| {
+            let freezer = Freezer::start(process, address, T::from_number(&value.value), interval);
+            self.freezes.push(Freeze { address, freezer });
+        });
+    }
+
+    /// Stop the background writer for `address`, if one is running.
+    pub fn unfreeze_value(&mut self, params: UnfreezeValueParams) {
+        self.stop_freeze(params.address);
+    }
+
+    /// Every address currently being kept frozen by a background writer.
+    pub fn list_frozen(&self) -> Vec<FrozenEntry> {
+        self.freezes
+            .iter()
+            .map(|freeze| FrozenEntry { address: freeze.address })
+            .collect()
+    }
+
+    /// Drops (and so stops and joins) the `Freezer` for `address`, if one is
+    /// running.
+    fn stop_freeze(&mut self, address: usize) {
+        if let Some(index) = self.freezes.iter().position(|freeze| freeze.address == address) {
+            self.freezes.remove(index);
         }
     }
 }
\ No newline at end of file
@@ -1,8 +1,65 @@
 use core::ops::Range;
-use std::sync::Arc;
+use std::fmt;
 
 pub type PID = i64;
 
+/// Failure modes shared by every platform's `Process` implementation, wrapping
+/// the OS-level errno/`GetLastError` instead of panicking.
+#[derive(Debug)]
+pub enum ProcessError {
+    Read { address: usize, len: usize, cause: String },
+    Write { address: usize, len: usize, cause: String },
+    OpenProcess { pid: PID, cause: String },
+    Attach { pid: PID, cause: String },
+    Query { address: usize, cause: String },
+    /// Querying or raising `RLIMIT_NOFILE` (or similar) failed, i.e. the
+    /// `getrlimit`/`setrlimit` call itself returned an error.
+    Limit { cause: String },
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::Read { address, len, cause } => {
+                write!(f, "failed to read {} bytes at {:#x}: {}", len, address, cause)
+            }
+            ProcessError::Write { address, len, cause } => {
+                write!(f, "failed to write {} bytes at {:#x}: {}", len, address, cause)
+            }
+            ProcessError::OpenProcess { pid, cause } => {
+                write!(f, "failed to open process {}: {}", pid, cause)
+            }
+            ProcessError::Attach { pid, cause } => {
+                write!(f, "failed to attach to process {}: {}", pid, cause)
+            }
+            ProcessError::Query { address, cause } => {
+                write!(f, "failed to query region at {:#x}: {}", address, cause)
+            }
+            ProcessError::Limit { cause } => {
+                write!(f, "failed to raise resource limit: {}", cause)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {}
+
+impl ProcessError {
+    /// A region that vanished or became unreadable mid-scan should just be
+    /// skipped; a process that is no longer running should abort the scan.
+    pub fn is_process_gone(&self) -> bool {
+        let cause = match self {
+            ProcessError::Read { cause, .. } => cause,
+            ProcessError::Write { cause, .. } => cause,
+            ProcessError::OpenProcess { cause, .. } => cause,
+            ProcessError::Attach { cause, .. } => cause,
+            ProcessError::Query { cause, .. } => cause,
+            ProcessError::Limit { cause, .. } => cause,
+        };
+        cause.contains("No such process") || cause.contains("ESRCH")
+    }
+}
+
 pub enum MemoryPermission {
     READONLY,
     READWRITE,
@@ -20,26 +77,87 @@ pub struct MemoryRegion {
     pub range: Range<usize>,
     pub permission: MemoryPermission,
     pub kind: MemoryKind,
+    /// Backing file for this mapping (the ELF/PE module it belongs to), if any.
+    pub path: Option<String>,
+}
+
+/// Coarse run state, mapped from whatever the OS exposes: Linux's
+/// `/proc/<pid>/status` `State` char, macOS's `proc_bsdinfo.pbi_status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessStatus {
+    Running,
+    Sleeping,
+    Zombie,
+    Stopped,
+    Unknown,
+}
+
+impl fmt::Display for ProcessStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ProcessStatus::Running => "running",
+            ProcessStatus::Sleeping => "sleeping",
+            ProcessStatus::Zombie => "zombie",
+            ProcessStatus::Stopped => "stopped",
+            ProcessStatus::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 pub trait Process: Send + std::marker::Sync {
     fn new(pid: PID) -> Self;
     fn pid(&self) -> PID;
     fn name(&self) -> String;
-    fn attach(&self) -> Result<(), String>;
-    fn detach(&self) -> Result<(), String>;
-    fn read_memory<T>(&self, offset: usize, buffer: *mut T) -> Result<(), String>;
-    fn read_memory_slice<T>(&self, offset: usize, buffer: &mut [T]) -> Result<(), String>;
-    fn write_memory<T>(&self, offset: usize, buffer: *const T) -> Result<(), String>;
-    fn write_memory_slice<T>(&self, offset: usize, buffer: &[T]) -> Result<(), String>;
+    /// Full argv the process was launched with, if it could still be read.
+    fn command_line(&self) -> Vec<String>;
+    /// Path to the process's executable, if it could still be read.
+    fn exe_path(&self) -> Option<String>;
+    /// PID of the parent process, if known.
+    fn parent_pid(&self) -> Option<PID>;
+    /// Whether the target is a 64-bit process (vs. 32-bit/WoW64).
+    fn is_64_bit(&self) -> bool;
+    /// Coarse run state (running/sleeping/zombie/stopped).
+    fn status(&self) -> ProcessStatus;
+    /// Real UID of the process owner, if known, so a front-end can tell
+    /// apart two processes sharing a name run by different users.
+    fn uid(&self) -> Option<u32>;
+    fn attach(&self) -> Result<(), ProcessError>;
+    fn detach(&self) -> Result<(), ProcessError>;
+    fn read_memory<T>(&self, offset: usize, buffer: *mut T) -> Result<(), ProcessError>;
+    fn read_memory_slice<T>(&self, offset: usize, buffer: &mut [T]) -> Result<(), ProcessError>;
+    fn write_memory<T>(&self, offset: usize, buffer: *const T) -> Result<(), ProcessError>;
+    fn write_memory_slice<T>(&self, offset: usize, buffer: &[T]) -> Result<(), ProcessError>;
+
+    /// Read many `(offset, len)` byte ranges at once. Each entry is `None` if
+    /// that particular range was unreadable (e.g. the region was freed since
+    /// the last pass); the whole call only fails once the process itself is
+    /// gone. The default issues one `read_memory_slice` per request;
+    /// platforms that can batch the underlying syscall (e.g. Linux's
+    /// `process_vm_readv` with multiple `iovec`s) should override this,
+    /// since callers like `Scanner::next_scan` use it to re-read thousands
+    /// of candidates per pass.
+    fn read_memory_regions(&self, requests: &[(usize, usize)]) -> Result<Vec<Option<Vec<u8>>>, ProcessError> {
+        requests
+            .iter()
+            .map(|&(offset, len)| {
+                let mut buffer = vec![0u8; len];
+                match self.read_memory_slice(offset, &mut buffer) {
+                    Ok(()) => Ok(Some(buffer)),
+                    Err(err) if err.is_process_gone() => Err(err),
+                    Err(_) => Ok(None),
+                }
+            })
+            .collect()
+    }
 }
 
 
-pub trait MemoryRegionIterator<P>: Iterator<Item = MemoryRegion>
+pub trait MemoryRegionIterator<'a, P>: Iterator<Item = MemoryRegion>
 where
     P: Process,
 {
-    fn new(process: Arc<P>, offset: usize, limit: usize) -> Self;
+    fn new(process: &'a P, offset: usize, limit: usize) -> Self;
 }
 
 pub trait ProcessIterator<P>: Iterator<Item = P>
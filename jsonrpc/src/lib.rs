@@ -11,7 +11,7 @@ pub struct ScannerModule<P, ProcessIter, MemoryRegionIter>
 where
     P: Process,
     ProcessIter: ProcessIterator<P>,
-    MemoryRegionIter: MemoryRegionIterator<P>,
+    MemoryRegionIter: for<'a> MemoryRegionIterator<'a, P>,
 {
     pub module: RpcModule<Mutex<ScannerContext<P>>>,
     process_iter: PhantomData<ProcessIter>,
@@ -22,12 +22,12 @@ impl<P, ProcessIter, MemoryRegionIter> Default for ScannerModule<P, ProcessIter,
 where
     P: Process + 'static,
     ProcessIter: ProcessIterator<P>,
-    MemoryRegionIter: MemoryRegionIterator<P>,
+    MemoryRegionIter: for<'a> MemoryRegionIterator<'a, P>,
 {
     fn default() -> Self
     where
         ProcessIter: ProcessIterator<P>,
-        MemoryRegionIter: MemoryRegionIterator<P>,
+        MemoryRegionIter: for<'a> MemoryRegionIterator<'a, P>,
     {
         let mut module: RpcModule<Mutex<ScannerContext<P>>> =
             RpcModule::new(Mutex::new(ScannerContext::default()));
@@ -1,11 +1,12 @@
 use core::{
     MemoryKind, MemoryPermission, MemoryRegion, MemoryRegionIterator as CoreMemoryRegionIterator,
-    Process as CoreProcess, ProcessIterator as CoreProcessIterator, PID,
+    Process as CoreProcess, ProcessError, ProcessIterator as CoreProcessIterator, ProcessStatus, PID,
 };
 use std::fs::{self, File, ReadDir};
 use std::io::{BufRead, BufReader, IoSlice, IoSliceMut};
 use std::mem::size_of;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use nix::sys::uio::{process_vm_readv, process_vm_writev, RemoteIoVec};
 use nix::sys::wait::{waitpid, WaitPidFlag, WaitStatus};
@@ -14,6 +15,7 @@ use nix::sys::wait::{waitpid, WaitPidFlag, WaitStatus};
 pub struct Process {
     proc_path: PathBuf,
     pid: PID,
+    suspended: AtomicBool,
 }
 
 impl CoreProcess for Process {
@@ -21,6 +23,7 @@ impl CoreProcess for Process {
         Self {
             proc_path: ["/proc", &pid.to_string()].iter().collect(),
             pid,
+            suspended: AtomicBool::new(false),
         }
     }
 
@@ -35,28 +38,107 @@ impl CoreProcess for Process {
             .to_string()
     }
 
-    fn attach(&self) -> Result<(), String> {
+    fn command_line(&self) -> Vec<String> {
+        fs::read(self.proc_path.join("cmdline"))
+            .map(|bytes| {
+                bytes
+                    .split(|&b| b == 0)
+                    .filter(|arg| !arg.is_empty())
+                    .map(|arg| String::from_utf8_lossy(arg).into_owned())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn exe_path(&self) -> Option<String> {
+        fs::read_link(self.proc_path.join("exe"))
+            .ok()
+            .map(|path| path.to_string_lossy().into_owned())
+    }
+
+    fn parent_pid(&self) -> Option<PID> {
+        let stat = fs::read_to_string(self.proc_path.join("stat")).ok()?;
+        // Fields before the 4th are `pid (comm) state`, but `comm` itself may
+        // contain spaces/parens, so split after the last ')' instead of by index.
+        let after_comm = stat.rsplit_once(')')?.1;
+        after_comm.split_whitespace().nth(1)?.parse().ok()
+    }
+
+    fn status(&self) -> ProcessStatus {
+        let contents = match fs::read_to_string(self.proc_path.join("status")) {
+            Ok(contents) => contents,
+            Err(_) => return ProcessStatus::Unknown,
+        };
+        // "State:\tR (running)", etc.
+        let state = contents
+            .lines()
+            .find_map(|line| line.strip_prefix("State:"))
+            .and_then(|rest| rest.trim_start().chars().next());
+
+        match state {
+            Some('R') => ProcessStatus::Running,
+            Some('S') | Some('D') | Some('I') => ProcessStatus::Sleeping,
+            Some('Z') => ProcessStatus::Zombie,
+            Some('T') | Some('t') => ProcessStatus::Stopped,
+            _ => ProcessStatus::Unknown,
+        }
+    }
+
+    fn uid(&self) -> Option<u32> {
+        let contents = fs::read_to_string(self.proc_path.join("status")).ok()?;
+        // "Uid:\t<real>\t<effective>\t<saved>\t<filesystem>"; the real UID is
+        // the first of the four.
+        let line = contents.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+        line.split_whitespace().next()?.parse().ok()
+    }
+
+    fn is_64_bit(&self) -> bool {
+        // ELF identification: e_ident[EI_CLASS] is byte 4, 2 == ELFCLASS64.
+        let mut header = [0u8; 5];
+        match fs::File::open(self.proc_path.join("exe")).and_then(|mut f| {
+            use std::io::Read;
+            f.read_exact(&mut header)
+        }) {
+            Ok(()) => header[4] == 2,
+            Err(_) => true,
+        }
+    }
+
+    fn attach(&self) -> Result<(), ProcessError> {
         use nix::{sys::ptrace, unistd::Pid};
 
         let pid = Pid::from_raw(self.pid() as i32);
 
-        ptrace::attach(pid).map_err(|op| op.desc().to_string())?;
+        ptrace::attach(pid).map_err(|op| ProcessError::Attach {
+            pid: self.pid(),
+            cause: op.desc().to_string(),
+        })?;
 
         match waitpid(pid, Some(WaitPidFlag::WSTOPPED)) {
             Ok(WaitStatus::Stopped(_, _)) => Ok(()),
-            Ok(x) => Err(format!("waitpid returned {:?}", x)),
-            Err(x) => Err(format!("waitpid returned {:?}", x)),
+            Ok(x) => Err(ProcessError::Attach {
+                pid: self.pid(),
+                cause: format!("waitpid returned {:?}", x),
+            }),
+            Err(x) => Err(ProcessError::Attach {
+                pid: self.pid(),
+                cause: format!("waitpid returned {:?}", x),
+            }),
         }
     }
 
-    fn detach(&self) -> Result<(), String> {
+    fn detach(&self) -> Result<(), ProcessError> {
         use nix::{
             sys::{ptrace, signal::Signal},
             unistd::Pid,
         };
 
-        ptrace::detach(Pid::from_raw(self.pid() as i32), Signal::SIGCONT)
-            .map_err(|op| op.desc().to_string())?;
+        ptrace::detach(Pid::from_raw(self.pid() as i32), Signal::SIGCONT).map_err(|op| {
+            ProcessError::Attach {
+                pid: self.pid(),
+                cause: op.desc().to_string(),
+            }
+        })?;
 
         // TODO: properly waitpid
         // match waitpid(pid, Some(WaitPidFlag::WCONTINUED)) {
@@ -67,7 +149,7 @@ impl CoreProcess for Process {
         return Ok(());
     }
 
-    fn read_memory<T>(&self, offset: usize, buffer: *mut T) -> Result<(), String> {
+    fn read_memory<T>(&self, offset: usize, buffer: *mut T) -> Result<(), ProcessError> {
         unsafe {
             let mut buffer_slice = std::slice::from_raw_parts_mut(buffer, size_of::<T>());
 
@@ -75,7 +157,7 @@ impl CoreProcess for Process {
         }
     }
 
-    fn read_memory_slice<T>(&self, start: usize, buffer: &mut [T]) -> Result<(), String> {
+    fn read_memory_slice<T>(&self, start: usize, buffer: &mut [T]) -> Result<(), ProcessError> {
         use nix::unistd::Pid;
 
         unsafe {
@@ -89,13 +171,80 @@ impl CoreProcess for Process {
             let remote = [RemoteIoVec { base: start, len }; 1];
 
             match process_vm_readv(Pid::from_raw(self.pid() as i32), &mut local, &remote) {
-                Ok(_) => Ok(()),
-                Err(errno) => Err(errno.desc().to_string()),
+                Ok(n) if n == len => return Ok(()),
+                _ => {}
             }
+
+            // process_vm_readv is disabled under yama ptrace_scope=1 for
+            // non-descendant tracers, or the read may simply straddle an
+            // unreadable page; fall back to a single positioned pread on
+            // /proc/<pid>/mem, which is a plain file read so it isn't
+            // affected by yama at all.
+            let read = self.pread_mem(start, bytes);
+            if read == len {
+                return Ok(());
+            }
+
+            // Last resort: a word-at-a-time PTRACE_PEEKDATA read, which works
+            // once the target is ptrace-attached even if /proc/<pid>/mem
+            // itself can't be opened (e.g. a setuid target).
+            let read = self.peek_data(start, bytes);
+            if read == len {
+                Ok(())
+            } else {
+                Err(ProcessError::Read {
+                    address: start,
+                    len,
+                    cause: format!("only {} of {} bytes were readable", read, len),
+                })
+            }
+        }
+    }
+
+    /// Fills `buffer` with `PTRACE_PEEKDATA`, one `c_long` word at a time,
+    /// stopping at the first unreadable word. Returns the number of bytes
+    /// actually filled so callers can tell a partial read from a clean one.
+    fn peek_data(&self, start: usize, buffer: &mut [u8]) -> usize {
+        use nix::{sys::ptrace, unistd::Pid};
+
+        let word_size = size_of::<std::os::raw::c_long>();
+        let pid = Pid::from_raw(self.pid() as i32);
+        let mut filled = 0;
+
+        while filled < buffer.len() {
+            let word_addr = (start + filled) as *mut std::os::raw::c_void;
+            let word = match ptrace::read(pid, word_addr) {
+                Ok(word) => word,
+                Err(_) => break,
+            };
+            let word_bytes = word.to_ne_bytes();
+
+            let n = std::cmp::min(word_size, buffer.len() - filled);
+            buffer[filled..filled + n].copy_from_slice(&word_bytes[..n]);
+            filled += n;
         }
+
+        filled
+    }
+
+    /// Fills `buffer` with a single positioned `pread64` on `/proc/<pid>/mem`.
+    /// Requires the same ptrace permission as `process_vm_readv`, but as a
+    /// plain file read it isn't subject to yama's `ptrace_scope` restriction
+    /// and, like `process_vm_readv`, comes up short rather than erroring when
+    /// the read straddles an unmapped page. Returns the number of bytes
+    /// actually filled.
+    fn pread_mem(&self, start: usize, buffer: &mut [u8]) -> usize {
+        use std::os::unix::fs::FileExt;
+
+        let file = match File::open(self.proc_path.join("mem")) {
+            Ok(file) => file,
+            Err(_) => return 0,
+        };
+
+        file.read_at(buffer, start as u64).unwrap_or(0)
     }
 
-    fn write_memory<T>(&self, offset: usize, buffer: *const T) -> Result<(), String> {
+    fn write_memory<T>(&self, offset: usize, buffer: *const T) -> Result<(), ProcessError> {
         unsafe {
             let buffer_slice = std::slice::from_raw_parts(buffer, size_of::<T>());
 
@@ -103,7 +252,7 @@ impl CoreProcess for Process {
         }
     }
 
-    fn write_memory_slice<T>(&self, start: usize, buffer: &[T]) -> Result<(), String> {
+    fn write_memory_slice<T>(&self, start: usize, buffer: &[T]) -> Result<(), ProcessError> {
         use nix::unistd::Pid;
 
         unsafe {
@@ -111,19 +260,146 @@ impl CoreProcess for Process {
                 buffer.as_ptr() as *const u8,
                 buffer.len() * size_of::<T>(),
             );
+            let len = bytes.len();
 
             let local = [IoSlice::new(bytes); 1];
-            let remote = [RemoteIoVec {
-                base: start,
-                len: bytes.len(),
-            }; 1];
+            let remote = [RemoteIoVec { base: start, len }; 1];
 
             match process_vm_writev(Pid::from_raw(self.pid() as i32), &local, &remote) {
                 Ok(_) => Ok(()),
-                Err(errno) => Err(errno.desc().to_string()),
+                Err(errno) => Err(ProcessError::Write {
+                    address: start,
+                    len,
+                    cause: errno.desc().to_string(),
+                }),
             }
         }
     }
+
+    /// Batched counterpart to `read_memory_slice`: one `process_vm_readv`
+    /// call carrying one `iovec` pair per request, instead of a syscall each,
+    /// which is what makes re-reading thousands of `next_scan` candidates
+    /// per pass affordable. `requests` is chunked to at most `IOV_MAX` iovecs
+    /// per syscall, since the kernel rejects a `process_vm_readv` call with
+    /// more than that.
+    fn read_memory_regions(&self, requests: &[(usize, usize)]) -> Result<Vec<Option<Vec<u8>>>, ProcessError> {
+        let mut results = Vec::with_capacity(requests.len());
+
+        for chunk in requests.chunks(IOV_MAX) {
+            results.extend(self.read_memory_regions_chunk(chunk)?);
+        }
+
+        Ok(results)
+    }
+}
+
+/// The kernel caps a single `readv`/`process_vm_readv` call at this many
+/// `iovec`s (`UIO_MAXIOV` in the kernel headers).
+const IOV_MAX: usize = 1024;
+
+impl Process {
+    fn read_memory_regions_chunk(&self, requests: &[(usize, usize)]) -> Result<Vec<Option<Vec<u8>>>, ProcessError> {
+        use nix::unistd::Pid;
+
+        let mut buffers: Vec<Vec<u8>> = requests.iter().map(|&(_, len)| vec![0u8; len]).collect();
+        let total_len: usize = requests.iter().map(|&(_, len)| len).sum();
+        let remote: Vec<RemoteIoVec> = requests
+            .iter()
+            .map(|&(offset, len)| RemoteIoVec { base: offset, len })
+            .collect();
+
+        let read = {
+            let mut local: Vec<IoSliceMut> =
+                buffers.iter_mut().map(|buffer| IoSliceMut::new(buffer)).collect();
+            process_vm_readv(Pid::from_raw(self.pid() as i32), &mut local, &remote)
+        };
+
+        if matches!(read, Ok(n) if n == total_len) {
+            return Ok(buffers.into_iter().map(Some).collect());
+        }
+
+        // process_vm_readv came up short (yama ptrace_scope=1, or some pages
+        // straddle an unreadable region); retry each request individually via
+        // /proc/<pid>/mem, falling back further to PTRACE_PEEKDATA, so only
+        // the candidates that actually vanished are dropped instead of
+        // failing the whole batch.
+        let mut results = Vec::with_capacity(requests.len());
+        for (&(offset, len), mut buffer) in requests.iter().zip(buffers) {
+            let mut filled = self.pread_mem(offset, &mut buffer);
+            if filled != len {
+                filled = self.peek_data(offset, &mut buffer);
+            }
+            if filled == len {
+                results.push(Some(buffer));
+            } else if self.proc_path.join("status").try_exists().unwrap_or(false) {
+                results.push(None);
+            } else {
+                return Err(ProcessError::Read {
+                    address: offset,
+                    len,
+                    cause: "No such process".to_string(),
+                });
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl Process {
+    /// PTRACE_ATTACH + waitpid(__WALL), mirroring the Windows
+    /// `suspend`/`resume` pair so the same target can be paused while a
+    /// caller does a read-modify-write (e.g. freezing a value).
+    pub fn suspend(&self) -> Result<(), ProcessError> {
+        use nix::{sys::ptrace, unistd::Pid};
+
+        let pid = Pid::from_raw(self.pid() as i32);
+
+        ptrace::attach(pid).map_err(|op| ProcessError::Attach {
+            pid: self.pid(),
+            cause: op.desc().to_string(),
+        })?;
+
+        match waitpid(pid, Some(WaitPidFlag::__WALL)) {
+            Ok(WaitStatus::Stopped(_, _)) => {
+                self.suspended.store(true, Ordering::Relaxed);
+                Ok(())
+            }
+            Ok(x) => Err(ProcessError::Attach {
+                pid: self.pid(),
+                cause: format!("waitpid returned {:?}", x),
+            }),
+            Err(x) => Err(ProcessError::Attach {
+                pid: self.pid(),
+                cause: format!("waitpid returned {:?}", x),
+            }),
+        }
+    }
+
+    pub fn resume(&self) -> Result<(), ProcessError> {
+        use nix::{
+            sys::{ptrace, signal::Signal},
+            unistd::Pid,
+        };
+
+        ptrace::detach(Pid::from_raw(self.pid() as i32), Signal::SIGCONT).map_err(|op| {
+            ProcessError::Attach {
+                pid: self.pid(),
+                cause: op.desc().to_string(),
+            }
+        })?;
+
+        self.suspended.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        if self.suspended.load(Ordering::Relaxed) {
+            let _ = self.resume();
+        }
+    }
 }
 
 pub struct ProcessIterator {
@@ -142,6 +418,27 @@ impl ProcessIterator {
             dirs: fs::read_dir("/proc").unwrap(),
         }
     }
+
+    /// Drops zombies (already exited, no readable address space) and kernel
+    /// threads (no `exe` link to read), which are never useful scan targets
+    /// and, left in, waste a `read_memory_regions` round-trip per entry.
+    pub fn scannable_only(self) -> impl Iterator<Item = Process> {
+        self.filter(|p| p.status() != ProcessStatus::Zombie && p.exe_path().is_some())
+    }
+}
+
+/// Raises `RLIMIT_NOFILE` to its hard limit. Walking every `/proc/<pid>/maps`
+/// entry and resolving each backing file's module info can hold far more
+/// file descriptors open at once than the default soft limit (often 1024)
+/// allows, so callers doing a full `/proc` sweep should call this first.
+pub fn raise_fd_limit() -> Result<(), ProcessError> {
+    use nix::sys::resource::{getrlimit, setrlimit, Resource};
+
+    let (_, hard) = getrlimit(Resource::RLIMIT_NOFILE)
+        .map_err(|errno| ProcessError::Limit { cause: errno.desc().to_string() })?;
+
+    setrlimit(Resource::RLIMIT_NOFILE, hard, hard)
+        .map_err(|errno| ProcessError::Limit { cause: errno.desc().to_string() })
 }
 
 impl CoreProcessIterator<Process> for ProcessIterator {}
@@ -202,16 +499,25 @@ impl<'a> Iterator for MemoryRegionIterator<'a> {
                     MemoryKind::STACK
                 } else if info.contains("heap") {
                     MemoryKind::HEAP
+                } else if info.starts_with('/') {
+                    MemoryKind::STATIC
                 } else if info.contains(self.process.name().as_str()) {
                     MemoryKind::UNKNOWN
                 } else {
                     continue;
                 };
 
+                let path = if info.starts_with('/') {
+                    Some(info.to_string())
+                } else {
+                    None
+                };
+
                 return Some(MemoryRegion {
                     range,
                     permission,
                     kind,
+                    path,
                 });
             } else if range.start - self.offset >= self.limit {
                 return None;
@@ -278,6 +584,29 @@ mod tests {
         child.kill().unwrap();
     }
 
+    #[test]
+    fn read_memory_regions_batched() {
+        let mut child = create_child();
+
+        let process = Process::new(child.id() as i64);
+
+        let requests: Vec<(usize, usize)> = MemoryRegionIterator::new(&process, 0, usize::MAX)
+            .filter(|region| {
+                matches!(
+                    region.permission,
+                    MemoryPermission::READONLY | MemoryPermission::READWRITE
+                )
+            })
+            .take(4)
+            .map(|region| (region.range.start, region.range.len().min(16)))
+            .collect();
+
+        let results = process.read_memory_regions(&requests).unwrap();
+        assert_eq!(results.len(), requests.len());
+
+        child.kill().unwrap();
+    }
+
     #[test]
     fn read_process_memory() {
         let mut child = create_child();
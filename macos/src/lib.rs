@@ -0,0 +1,431 @@
+use core::{
+    MemoryKind, MemoryPermission, MemoryRegion, MemoryRegionIterator as CoreMemoryRegionIterator,
+    Process as CoreProcess, ProcessError, ProcessIterator as CoreProcessIterator, ProcessStatus, PID,
+};
+use std::mem::size_of;
+
+use mach2::kern_return::KERN_SUCCESS;
+use mach2::mach_types::task_t;
+use mach2::message::mach_msg_type_number_t;
+use mach2::port::{mach_port_t, MACH_PORT_NULL};
+use mach2::traps::{mach_task_self, task_for_pid};
+use mach2::vm::{mach_vm_read_overwrite, mach_vm_region, mach_vm_write};
+use mach2::vm_prot::{VM_PROT_READ, VM_PROT_WRITE};
+use mach2::vm_region::{vm_region_basic_info_64, VM_REGION_BASIC_INFO_64};
+use mach2::vm_types::{mach_vm_address_t, mach_vm_size_t};
+
+#[derive(Debug)]
+pub struct Process {
+    pid: PID,
+    task: task_t,
+}
+
+impl CoreProcess for Process {
+    fn new(pid: PID) -> Self {
+        let mut task: task_t = MACH_PORT_NULL;
+
+        unsafe {
+            task_for_pid(mach_task_self(), pid as i32, &mut task);
+        }
+
+        Self { pid, task }
+    }
+
+    fn pid(&self) -> PID {
+        self.pid
+    }
+
+    fn name(&self) -> String {
+        let mut path_buf = [0u8; libc::PROC_PIDPATHINFO_MAXSIZE as usize];
+
+        unsafe {
+            let len = libc::proc_pidpath(
+                self.pid as i32,
+                path_buf.as_mut_ptr() as *mut libc::c_void,
+                path_buf.len() as u32,
+            );
+
+            if len <= 0 {
+                return String::new();
+            }
+
+            String::from_utf8_lossy(&path_buf[..len as usize])
+                .rsplit('/')
+                .next()
+                .unwrap_or("")
+                .to_string()
+        }
+    }
+
+    fn command_line(&self) -> Vec<String> {
+        // KERN_PROCARGS2 returns argc followed by the NUL-separated
+        // exec_path/argv/envp block; only argv is surfaced here.
+        let mut size: libc::size_t = 0;
+        let mut mib = [libc::CTL_KERN, libc::KERN_PROCARGS2, self.pid as i32];
+
+        unsafe {
+            if libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as u32,
+                std::ptr::null_mut(),
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            ) != 0
+                || size == 0
+            {
+                return Vec::new();
+            }
+
+            let mut buf = vec![0u8; size];
+            if libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as u32,
+                buf.as_mut_ptr() as *mut libc::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            ) != 0
+            {
+                return Vec::new();
+            }
+
+            if buf.len() < size_of::<libc::c_int>() {
+                return Vec::new();
+            }
+            let argc = i32::from_ne_bytes(buf[0..4].try_into().unwrap());
+
+            // Skip argc, then the exec_path (NUL-terminated), then any
+            // padding NULs before argv[0] starts.
+            let mut offset = size_of::<libc::c_int>();
+            while offset < buf.len() && buf[offset] != 0 {
+                offset += 1;
+            }
+            while offset < buf.len() && buf[offset] == 0 {
+                offset += 1;
+            }
+
+            let mut args = Vec::new();
+            for _ in 0..argc {
+                let start = offset;
+                while offset < buf.len() && buf[offset] != 0 {
+                    offset += 1;
+                }
+                args.push(String::from_utf8_lossy(&buf[start..offset]).into_owned());
+                offset += 1;
+            }
+            args
+        }
+    }
+
+    fn exe_path(&self) -> Option<String> {
+        let mut path_buf = [0u8; libc::PROC_PIDPATHINFO_MAXSIZE as usize];
+
+        unsafe {
+            let len = libc::proc_pidpath(
+                self.pid as i32,
+                path_buf.as_mut_ptr() as *mut libc::c_void,
+                path_buf.len() as u32,
+            );
+
+            if len <= 0 {
+                None
+            } else {
+                Some(String::from_utf8_lossy(&path_buf[..len as usize]).into_owned())
+            }
+        }
+    }
+
+    fn parent_pid(&self) -> Option<PID> {
+        let mut info: libc::proc_bsdinfo = unsafe { std::mem::zeroed() };
+
+        let ret = unsafe {
+            libc::proc_pidinfo(
+                self.pid as i32,
+                libc::PROC_PIDTBSDINFO,
+                0,
+                &mut info as *mut _ as *mut libc::c_void,
+                size_of::<libc::proc_bsdinfo>() as i32,
+            )
+        };
+
+        if ret as usize == size_of::<libc::proc_bsdinfo>() {
+            Some(info.pbi_ppid as PID)
+        } else {
+            None
+        }
+    }
+
+    fn is_64_bit(&self) -> bool {
+        let mut info: libc::proc_bsdinfo = unsafe { std::mem::zeroed() };
+
+        let ret = unsafe {
+            libc::proc_pidinfo(
+                self.pid as i32,
+                libc::PROC_PIDTBSDINFO,
+                0,
+                &mut info as *mut _ as *mut libc::c_void,
+                size_of::<libc::proc_bsdinfo>() as i32,
+            )
+        };
+
+        ret as usize == size_of::<libc::proc_bsdinfo>() && info.pbi_flags & libc::PROC_FLAG_LP64 != 0
+    }
+
+    fn uid(&self) -> Option<u32> {
+        let mut info: libc::proc_bsdinfo = unsafe { std::mem::zeroed() };
+
+        let ret = unsafe {
+            libc::proc_pidinfo(
+                self.pid as i32,
+                libc::PROC_PIDTBSDINFO,
+                0,
+                &mut info as *mut _ as *mut libc::c_void,
+                size_of::<libc::proc_bsdinfo>() as i32,
+            )
+        };
+
+        if ret as usize == size_of::<libc::proc_bsdinfo>() {
+            Some(info.pbi_uid)
+        } else {
+            None
+        }
+    }
+
+    fn status(&self) -> ProcessStatus {
+        let mut info: libc::proc_bsdinfo = unsafe { std::mem::zeroed() };
+
+        let ret = unsafe {
+            libc::proc_pidinfo(
+                self.pid as i32,
+                libc::PROC_PIDTBSDINFO,
+                0,
+                &mut info as *mut _ as *mut libc::c_void,
+                size_of::<libc::proc_bsdinfo>() as i32,
+            )
+        };
+
+        if ret as usize != size_of::<libc::proc_bsdinfo>() {
+            return ProcessStatus::Unknown;
+        }
+
+        match info.pbi_status as i32 {
+            libc::SRUN => ProcessStatus::Running,
+            libc::SSLEEP | libc::SIDL => ProcessStatus::Sleeping,
+            libc::SZOMB => ProcessStatus::Zombie,
+            libc::SSTOP => ProcessStatus::Stopped,
+            _ => ProcessStatus::Unknown,
+        }
+    }
+
+    fn attach(&self) -> Result<(), ProcessError> {
+        // task_for_pid already grants read/write access to the target's
+        // address space; macOS has no separate ptrace-style attach step.
+        if self.task == MACH_PORT_NULL as task_t {
+            Err(ProcessError::Attach {
+                pid: self.pid,
+                cause: "task_for_pid failed (missing entitlement or privilege?)".to_string(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn detach(&self) -> Result<(), ProcessError> {
+        Ok(())
+    }
+
+    fn read_memory<T>(&self, offset: usize, buffer: *mut T) -> Result<(), ProcessError> {
+        unsafe {
+            let buffer_slice = std::slice::from_raw_parts_mut(buffer, size_of::<T>());
+            self.read_memory_slice(offset, buffer_slice)
+        }
+    }
+
+    fn read_memory_slice<T>(&self, start: usize, buffer: &mut [T]) -> Result<(), ProcessError> {
+        let len = buffer.len() * size_of::<T>();
+        let mut read_len: mach_vm_size_t = 0;
+
+        let kr = unsafe {
+            mach_vm_read_overwrite(
+                self.task,
+                start as mach_vm_address_t,
+                len as mach_vm_size_t,
+                buffer.as_mut_ptr() as mach_vm_address_t,
+                &mut read_len,
+            )
+        };
+
+        if kr == KERN_SUCCESS {
+            Ok(())
+        } else {
+            Err(ProcessError::Read {
+                address: start,
+                len,
+                cause: format!("mach_vm_read_overwrite returned kern_return_t {}", kr),
+            })
+        }
+    }
+
+    fn write_memory<T>(&self, offset: usize, buffer: *const T) -> Result<(), ProcessError> {
+        unsafe {
+            let buffer_slice = std::slice::from_raw_parts(buffer, size_of::<T>());
+            self.write_memory_slice(offset, buffer_slice)
+        }
+    }
+
+    fn write_memory_slice<T>(&self, start: usize, buffer: &[T]) -> Result<(), ProcessError> {
+        let len = buffer.len() * size_of::<T>();
+
+        let kr = unsafe {
+            mach_vm_write(
+                self.task,
+                start as mach_vm_address_t,
+                buffer.as_ptr() as mach_vm_address_t,
+                len as mach_msg_type_number_t,
+            )
+        };
+
+        if kr == KERN_SUCCESS {
+            Ok(())
+        } else {
+            Err(ProcessError::Write {
+                address: start,
+                len,
+                cause: format!("mach_vm_write returned kern_return_t {}", kr),
+            })
+        }
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        unsafe {
+            if self.task != MACH_PORT_NULL as task_t {
+                mach2::port::mach_port_deallocate(mach_task_self(), self.task as mach_port_t);
+            }
+        }
+    }
+}
+
+pub struct ProcessIterator {
+    pids: std::vec::IntoIter<libc::pid_t>,
+}
+
+impl ProcessIterator {
+    pub fn new() -> Self {
+        let count = unsafe { libc::proc_listpids(libc::PROC_ALL_PIDS, 0, std::ptr::null_mut(), 0) };
+        let capacity = (count as usize) / size_of::<libc::pid_t>();
+        let mut pids = vec![0 as libc::pid_t; capacity.max(1)];
+
+        let bytes = unsafe {
+            libc::proc_listpids(
+                libc::PROC_ALL_PIDS,
+                0,
+                pids.as_mut_ptr() as *mut libc::c_void,
+                (pids.len() * size_of::<libc::pid_t>()) as i32,
+            )
+        };
+
+        let actual = (bytes.max(0) as usize) / size_of::<libc::pid_t>();
+        pids.truncate(actual);
+
+        Self { pids: pids.into_iter() }
+    }
+}
+
+impl Default for ProcessIterator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoreProcessIterator<Process> for ProcessIterator {
+    fn new() -> Self {
+        ProcessIterator::new()
+    }
+}
+
+impl Iterator for ProcessIterator {
+    type Item = Process;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pids
+            .find(|&pid| pid != 0)
+            .map(|pid| Process::new(pid as PID))
+    }
+}
+
+pub struct MemoryRegionIterator<'a> {
+    address: mach_vm_address_t,
+    offset: usize,
+    limit: usize,
+    process: &'a Process,
+}
+
+impl<'a> CoreMemoryRegionIterator<'a, Process> for MemoryRegionIterator<'a> {
+    fn new(process: &'a Process, offset: usize, limit: usize) -> Self {
+        Self {
+            address: offset as mach_vm_address_t,
+            offset,
+            limit,
+            process,
+        }
+    }
+}
+
+impl<'a> Iterator for MemoryRegionIterator<'a> {
+    type Item = MemoryRegion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut address = self.address;
+            let mut size: mach_vm_size_t = 0;
+            let mut info = vm_region_basic_info_64::default();
+            let mut info_count = (size_of::<vm_region_basic_info_64>() / size_of::<u32>())
+                as mach_msg_type_number_t;
+            let mut object_name: mach_port_t = MACH_PORT_NULL;
+
+            let kr = unsafe {
+                mach_vm_region(
+                    self.process.task,
+                    &mut address,
+                    &mut size,
+                    VM_REGION_BASIC_INFO_64,
+                    &mut info as *mut _ as *mut i32,
+                    &mut info_count,
+                    &mut object_name,
+                )
+            };
+
+            if kr != KERN_SUCCESS {
+                // Either the region table is exhausted, or task_for_pid never
+                // succeeded (no entitlement/privilege) -- either way there are
+                // no more readable regions to report.
+                return None;
+            }
+
+            let range = address as usize..(address as usize + size as usize);
+            self.address = address + size;
+
+            if range.start - self.offset >= self.limit {
+                return None;
+            }
+
+            let permission = match (info.protection & VM_PROT_READ != 0, info.protection & VM_PROT_WRITE != 0) {
+                (true, true) => MemoryPermission::READWRITE,
+                (true, false) => MemoryPermission::READONLY,
+                _ => MemoryPermission::NONE,
+            };
+
+            return Some(MemoryRegion {
+                range,
+                permission,
+                kind: MemoryKind::UNKNOWN,
+                // `mach_vm_region` doesn't carry a backing path; resolving one
+                // would need a second `proc_regionfilename` call per region.
+                path: None,
+            });
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::ops::Range;
+
+use goblin::elf::header::ET_EXEC;
+use goblin::elf::program_header::PT_NOTE;
+use goblin::Object;
+
+/// GNU build-id note type, as written to a `PT_NOTE` segment named `"GNU"`.
+const NT_GNU_BUILD_ID: u32 = 3;
+
+/// A single loaded object file, keyed by its lowest mapped address.
+pub struct Module {
+    pub name: String,
+    pub path: String,
+    pub base: usize,
+    pub size: usize,
+    /// GNU build-id (ELF) or nothing (PE has no equivalent notion here), used
+    /// to key a module to a stable identity independent of its load address.
+    pub build_id: Option<Vec<u8>>,
+    symbols: BTreeMap<usize, String>,
+    /// Non-overlapping `(name, address range)` pairs such as `.text`/`.data`,
+    /// in the order the object file declared them.
+    sections: Vec<(String, Range<usize>)>,
+}
+
+/// Resolves absolute addresses to `module_name+offset` (and back) so scan
+/// results stay meaningful across restarts despite ASLR, by parsing the ELF
+/// (Linux) or PE (Windows) headers of each mapped file with `goblin`.
+#[derive(Default)]
+pub struct ModuleMap {
+    modules: Vec<Module>,
+}
+
+impl ModuleMap {
+    pub fn new() -> Self {
+        Self { modules: Vec::new() }
+    }
+
+    /// Build a map from `(path, base_address, size)` triples, one per
+    /// distinct backing file a `MemoryRegionIterator` walked over. Modules
+    /// whose file can't be read or parsed are skipped rather than aborting
+    /// the whole map.
+    pub fn from_regions<I: IntoIterator<Item = (String, usize, usize)>>(regions: I) -> Self {
+        let mut modules = Vec::new();
+
+        for (path, base, size) in regions {
+            let name = path.rsplit('/').next().unwrap_or(&path).to_string();
+            let info = read_module_info(&path, base).unwrap_or_default();
+
+            modules.push(Module {
+                name,
+                path,
+                base,
+                size,
+                build_id: info.build_id,
+                symbols: info.symbols,
+                sections: info.sections,
+            });
+        }
+
+        Self { modules }
+    }
+
+    /// Translate an absolute address into `(module_name, offset)`.
+    pub fn resolve(&self, address: usize) -> Option<(&str, usize)> {
+        self.modules
+            .iter()
+            .find(|m| address >= m.base && address < m.base + m.size)
+            .map(|m| (m.name.as_str(), address - m.base))
+    }
+
+    /// Re-resolve a previously saved `module_name+offset` pair back into an
+    /// absolute address after the process was relaunched (and thus the
+    /// module's base may have moved due to ASLR).
+    pub fn unresolve(&self, module_name: &str, offset: usize) -> Option<usize> {
+        self.modules
+            .iter()
+            .find(|m| m.name == module_name)
+            .map(|m| m.base + offset)
+    }
+
+    /// Look up a module by name, e.g. to restrict a scan to its address range.
+    pub fn find(&self, name: &str) -> Option<&Module> {
+        self.modules.iter().find(|m| m.name == name)
+    }
+
+    /// Every module currently known to the map, e.g. for a `list_modules` RPC.
+    pub fn modules(&self) -> &[Module] {
+        &self.modules[..]
+    }
+
+    /// Name of the nearest known symbol at or below `address` within the
+    /// module containing it. Symbol sizes are not tracked, so `address` may
+    /// lie past the end of the symbol returned.
+    pub fn symbol_at(&self, address: usize) -> Option<&str> {
+        let module = self
+            .modules
+            .iter()
+            .find(|m| address >= m.base && address < m.base + m.size)?;
+
+        module
+            .symbols
+            .range(module.base..=address)
+            .next_back()
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Name of the section (e.g. `.text`, `.data`) whose range contains
+    /// `address`, so a scan result can be labelled "code" vs. "writable data"
+    /// without the caller needing to know the object format.
+    pub fn section_at(&self, address: usize) -> Option<&str> {
+        let module = self
+            .modules
+            .iter()
+            .find(|m| address >= m.base && address < m.base + m.size)?;
+
+        module.section_at(address)
+    }
+}
+
+impl Module {
+    /// Name of the section whose range contains `address`, if any.
+    pub fn section_at(&self, address: usize) -> Option<&str> {
+        self.sections
+            .iter()
+            .find(|(_, range)| range.contains(&address))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+#[derive(Default)]
+struct ModuleInfo {
+    symbols: BTreeMap<usize, String>,
+    sections: Vec<(String, Range<usize>)>,
+    build_id: Option<Vec<u8>>,
+}
+
+fn read_module_info(path: &str, base: usize) -> Option<ModuleInfo> {
+    let bytes = fs::read(path).ok()?;
+
+    match Object::parse(&bytes).ok()? {
+        Object::Elf(elf) => {
+            // `st_value`/`sh_addr` are load-relative vaddrs for a PIE
+            // (`ET_DYN`), so they need `base` added to land at the address
+            // the loader actually mapped them to. A non-PIE `ET_EXEC` has
+            // already-absolute vaddrs (e.g. `0x400000`) baked into the
+            // binary, so adding `base` there would double-count it.
+            let load_base = if elf.header.e_type == ET_EXEC { 0 } else { base };
+
+            let mut symbols = BTreeMap::new();
+
+            for sym in elf.syms.iter().chain(elf.dynsyms.iter()) {
+                if sym.st_value == 0 || sym.st_name == 0 {
+                    continue;
+                }
+                if let Some(name) = elf.strtab.get_at(sym.st_name).or_else(|| elf.dynstrtab.get_at(sym.st_name)) {
+                    symbols.insert(load_base + sym.st_value as usize, name.to_string());
+                }
+            }
+
+            let mut sections = Vec::new();
+            for shdr in &elf.section_headers {
+                if shdr.sh_addr == 0 {
+                    // Not loaded into the process's address space (e.g. `.symtab`).
+                    continue;
+                }
+                if let Some(name) = elf.shdr_strtab.get_at(shdr.sh_name) {
+                    if name.is_empty() {
+                        continue;
+                    }
+                    let start = load_base + shdr.sh_addr as usize;
+                    sections.push((name.to_string(), start..start + shdr.sh_size as usize));
+                }
+            }
+
+            let build_id = elf
+                .program_headers
+                .iter()
+                .find(|phdr| phdr.p_type == PT_NOTE)
+                .and_then(|phdr| {
+                    let start = phdr.p_offset as usize;
+                    let end = start + phdr.p_filesz as usize;
+                    bytes.get(start..end)
+                })
+                .and_then(parse_gnu_build_id);
+
+            Some(ModuleInfo { symbols, sections, build_id })
+        }
+        Object::PE(pe) => {
+            let mut symbols = BTreeMap::new();
+
+            for export in pe.exports {
+                if let (Some(name), Some(rva)) = (export.name, Some(export.rva)) {
+                    symbols.insert(base + rva, name.to_string());
+                }
+            }
+
+            let sections = pe
+                .sections
+                .iter()
+                .map(|section| {
+                    let name = section.name().unwrap_or("").trim_end_matches('\0').to_string();
+                    let start = base + section.virtual_address as usize;
+                    (name, start..start + section.virtual_size as usize)
+                })
+                .collect();
+
+            Some(ModuleInfo { symbols, sections, build_id: None })
+        }
+        _ => None,
+    }
+}
+
+/// Walk a `PT_NOTE` segment's raw bytes looking for the GNU build-id note
+/// (`name == "GNU\0"`, `n_type == NT_GNU_BUILD_ID`), per the ELF note format:
+/// `namesz`, `descsz`, `type` (each 4 bytes), then `name` and `desc`, each
+/// padded up to a 4-byte boundary.
+fn parse_gnu_build_id(notes: &[u8]) -> Option<Vec<u8>> {
+    let mut offset = 0;
+    while offset + 12 <= notes.len() {
+        let namesz = u32::from_ne_bytes(notes[offset..offset + 4].try_into().ok()?) as usize;
+        let descsz = u32::from_ne_bytes(notes[offset + 4..offset + 8].try_into().ok()?) as usize;
+        let n_type = u32::from_ne_bytes(notes[offset + 8..offset + 12].try_into().ok()?);
+        offset += 12;
+
+        let name_end = offset + namesz;
+        let name = notes.get(offset..name_end)?;
+        offset += (namesz + 3) / 4 * 4;
+
+        let desc_end = offset + descsz;
+        let desc = notes.get(offset..desc_end)?.to_vec();
+        offset += (descsz + 3) / 4 * 4;
+
+        if n_type == NT_GNU_BUILD_ID && name.starts_with(b"GNU\0") {
+            return Some(desc);
+        }
+    }
+    None
+}
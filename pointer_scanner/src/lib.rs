@@ -0,0 +1,124 @@
+use core::{MemoryPermission, MemoryRegionIterator, Process, ProcessError};
+use std::collections::BTreeMap;
+use std::mem::size_of;
+use std::ops::Range;
+use std::sync::Arc;
+
+use modules::ModuleMap;
+
+/// A pointer path from a module's static base to a dynamic target address
+/// that survives relaunching the process, found by `PointerScanner`:
+/// starting from `module_base + base_offset`, dereference and add each of
+/// `offsets` in turn to land on the target, e.g.
+/// `*(*(module_base + base_offset) + offsets[0]) + offsets[1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointerChain {
+    pub module_name: String,
+    pub base_offset: usize,
+    pub offsets: Vec<usize>,
+}
+
+/// Finds pointer chains rooted at a static module address that resolve to a
+/// dynamic target, by working backwards from every writable-memory pointer
+/// that points into the target's neighbourhood.
+pub struct PointerScanner<P: Process> {
+    process: Arc<P>,
+}
+
+impl<P: Process> PointerScanner<P> {
+    pub fn new(process: Arc<P>) -> Self {
+        Self { process }
+    }
+
+    /// Search for chains `module_base + o0 -> *+o1 -> ... -> target`, where
+    /// each hop's offset is at most `max_offset` and a chain is at most
+    /// `max_depth` pointers deep.
+    ///
+    /// First builds a map of every pointer-sized, aligned value found in a
+    /// writable region whose value itself lands inside some known region
+    /// (i.e. looks like a pointer), keyed by the address it points to. Then
+    /// does a bounded reverse search from `target`: at each level, any
+    /// stored pointer whose value falls in `[target - max_offset, target]`
+    /// becomes a candidate hop (the delta is the offset); if the address
+    /// holding that pointer falls inside a module's static range, that's a
+    /// finished chain, otherwise recurse on it as the new target.
+    pub fn find_chains<'a, M: MemoryRegionIterator<'a, P>>(
+        &'a self,
+        modules: &ModuleMap,
+        target: usize,
+        max_depth: usize,
+        max_offset: usize,
+    ) -> Result<Vec<PointerChain>, ProcessError> {
+        let pointer_size = size_of::<usize>();
+
+        let mut known_ranges: Vec<Range<usize>> = Vec::new();
+        let mut writable_ranges: Vec<Range<usize>> = Vec::new();
+        for region in M::new(self.process.as_ref(), 0, usize::MAX) {
+            if matches!(region.permission, MemoryPermission::READWRITE) {
+                writable_ranges.push(region.range.clone());
+            }
+            known_ranges.push(region.range);
+        }
+
+        let mut pointers_to: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for range in &writable_ranges {
+            let mut buffer = vec![0u8; range.len()];
+            match self.process.read_memory_slice(range.start, &mut buffer) {
+                Ok(_) => {}
+                Err(err) if err.is_process_gone() => return Err(err),
+                Err(_) => continue,
+            }
+
+            let aligned_len = buffer.len().saturating_sub(pointer_size - 1);
+            for offset in (0..aligned_len).step_by(pointer_size) {
+                let value = usize::from_ne_bytes(
+                    buffer[offset..offset + pointer_size].try_into().unwrap(),
+                );
+                if known_ranges.iter().any(|known| known.contains(&value)) {
+                    pointers_to.entry(value).or_default().push(range.start + offset);
+                }
+            }
+        }
+
+        let mut results = Vec::new();
+        let mut path = Vec::new();
+        search_level(&pointers_to, modules, target, max_offset, max_depth, &mut path, &mut results);
+
+        Ok(results)
+    }
+}
+
+fn search_level(
+    pointers_to: &BTreeMap<usize, Vec<usize>>,
+    modules: &ModuleMap,
+    target: usize,
+    max_offset: usize,
+    depth_remaining: usize,
+    path: &mut Vec<usize>,
+    results: &mut Vec<PointerChain>,
+) {
+    if depth_remaining == 0 {
+        return;
+    }
+
+    let low = target.saturating_sub(max_offset);
+    for (&pointed_to, holders) in pointers_to.range(low..=target) {
+        let offset = target - pointed_to;
+
+        for &holder in holders {
+            path.push(offset);
+
+            if let Some((module_name, base_offset)) = modules.resolve(holder) {
+                results.push(PointerChain {
+                    module_name: module_name.to_string(),
+                    base_offset,
+                    offsets: path.iter().rev().copied().collect(),
+                });
+            } else {
+                search_level(pointers_to, modules, holder, max_offset, depth_remaining - 1, path, results);
+            }
+
+            path.pop();
+        }
+    }
+}
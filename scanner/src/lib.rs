@@ -1,5 +1,110 @@
-use core::{MemoryRegionIterator, Process};
-use std::{marker::PhantomData, mem::size_of, slice::Iter, sync::Arc};
+//! `Scanner<P>` is generic over `core::Process`, the platform abstraction
+//! (`fn read_memory_slice`/`write_memory_slice`/`read_memory_regions` plus a
+//! per-platform `MemoryRegionIterator`) that the `linux` and `macos` crates
+//! implement independently of `windows_core`, so the same
+//! `new_scan`/`next_scan_compare`/`pattern_scan` logic runs on Linux and
+//! macOS and can be exercised in CI without a Windows host.
+//! `windows_core::Process` does not implement `core::Process`, so `Scanner`
+//! does not run on Windows.
+//! `core::MemoryRegionIterator<'a, P>` borrows the process for `'a` rather
+//! than taking an `Arc<P>`, matching the `&'a P` every implementation
+//! (`linux`/`macos`) and caller (`Scanner`/`PointerScanner`) actually use.
+
+use core::{MemoryRegionIterator, Process, ProcessError};
+use std::{
+    iter::{Skip, Take},
+    marker::PhantomData,
+    mem::size_of,
+    ops::Sub,
+    slice::Iter,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use bytemuck::{bytes_of, pod_read_unaligned, Pod};
+use rayon::prelude::*;
+
+/// Default chunk size `new_scan` streams each region in, overridable via
+/// `Scanner::set_chunk_size`. Small enough to keep peak memory bounded on
+/// multi-GB regions, large enough to amortize the per-chunk read syscall.
+const DEFAULT_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Relative tolerance `f32`/`f64` scan equality allows: a value the user
+/// enters as e.g. `19.9` and the same value read back out of the target's
+/// memory rarely share a bit pattern after a lossy round-trip through
+/// `f32`/`f64`, so `Scannable::scan_eq` compares floats within this fraction
+/// of the larger magnitude (or of 1.0, for magnitudes below 1.0) instead of
+/// requiring bit-exact equality.
+const FLOAT_SCAN_EPSILON: f64 = 1e-5;
+
+/// Cheat-Engine-style "unknown initial value" comparators. `Increased`,
+/// `Decreased`, `Changed`, `Unchanged`, `IncreasedBy` and `DecreasedBy` are
+/// tested against the value recorded for an address on the *previous* pass;
+/// `Equal`, `GreaterThan`, `LessThan` and `Between` test the freshly read
+/// value against the literal(s) they carry.
+pub enum Comparator<T> {
+    Increased,
+    Decreased,
+    Changed,
+    Unchanged,
+    IncreasedBy(T),
+    DecreasedBy(T),
+    Equal(T),
+    GreaterThan(T),
+    LessThan(T),
+    Between(T, T),
+}
+
+/// A value type a scan can reinterpret raw memory bytes as: `bytemuck::Pod`
+/// (trivially copyable, no padding, valid for any bit pattern) so every read
+/// goes through `bytemuck::pod_read_unaligned` instead of a pointer cast —
+/// `Scanner` has no alignment guarantee on the offsets it scans at, and
+/// casting a misaligned `&[u8]` to `&T` is UB for any `T` with alignment > 1.
+/// `bytemuck::CheckedBitPattern` exists for types with bit patterns `Pod`
+/// can't unconditionally accept (e.g. `bool`), but every type
+/// `context::ScanValueType` selects is already unconditionally valid for any
+/// bits, so plain `Pod` is enough here.
+pub trait Scannable: Pod {
+    /// Equality used by exact-value scans and
+    /// `Comparator::{Changed,Unchanged,Equal,IncreasedBy,DecreasedBy}`:
+    /// bit-exact for integers, within `FLOAT_SCAN_EPSILON` for floats.
+    fn scan_eq(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_scannable_int {
+    ($($t:ty),* $(,)?) => {
+        $(impl Scannable for $t {
+            fn scan_eq(&self, other: &Self) -> bool {
+                self == other
+            }
+        })*
+    };
+}
+
+impl_scannable_int!(i8, u8, i16, u16, i32, u32, i64, u64);
+
+impl Scannable for f32 {
+    fn scan_eq(&self, other: &Self) -> bool {
+        let (a, b) = (*self as f64, *other as f64);
+        (a - b).abs() <= FLOAT_SCAN_EPSILON * a.abs().max(b.abs()).max(1.0)
+    }
+}
+
+impl Scannable for f64 {
+    fn scan_eq(&self, other: &Self) -> bool {
+        (self - other).abs() <= FLOAT_SCAN_EPSILON * self.abs().max(other.abs()).max(1.0)
+    }
+}
+
+/// Read a `T` out of the leading `size_of::<T>()` bytes of `bytes`, without
+/// requiring `bytes` to be aligned for `T`.
+fn read_value<T: Scannable>(bytes: &[u8]) -> T {
+    pod_read_unaligned(&bytes[..size_of::<T>()])
+}
 
 pub struct Scanner<P>
 where
@@ -8,6 +113,10 @@ where
     process: Arc<P>,
     addresses: Vec<usize>,
     value_size: usize,
+    // Last-seen value at each address, index-aligned with `addresses`;
+    // only populated once `new_scan_unknown` has been used.
+    snapshot: Vec<u8>,
+    chunk_size: usize,
 }
 
 impl<P: Process> Scanner<P> {
@@ -16,6 +125,8 @@ impl<P: Process> Scanner<P> {
             process,
             addresses: Vec::new(),
             value_size: 0,
+            snapshot: Vec::new(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
         }
     }
 
@@ -23,73 +134,350 @@ impl<P: Process> Scanner<P> {
         &self.addresses[..]
     }
 
-    pub fn new_scan<'a, T: PartialEq, F: FnMut(&T) -> bool, M: MemoryRegionIterator<'a, P>>(
-        &'a mut self,
+    /// Overrides the chunk size `new_scan` streams each region in. Smaller
+    /// chunks bound peak memory on huge regions at the cost of more, smaller
+    /// reads and less work per parallel task.
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.chunk_size = chunk_size;
+    }
+
+    /// Streams each region in `chunk_size`-sized chunks (instead of
+    /// allocating the whole region up front, which blows memory on multi-GB
+    /// regions) and scans chunks from every region across a rayon thread
+    /// pool. Consecutive chunks overlap by `size_of::<T>() - 1` bytes so a
+    /// match straddling a chunk boundary is still fully contained in one
+    /// chunk's buffer; a chunk whose read fails is skipped rather than
+    /// aborting the whole scan, unless the process itself is gone.
+    pub fn new_scan<'a, T, F, M>(&'a mut self, predicate: F, start: usize, limit: usize) -> Result<(), ProcessError>
+    where
+        T: Scannable,
+        F: Fn(&T) -> bool + Sync,
+        M: MemoryRegionIterator<'a, P>,
+    {
+        self.addresses.clear();
+        self.snapshot.clear();
+        let value_size = size_of::<T>();
+        self.value_size = value_size;
+
+        let overlap = value_size.saturating_sub(1);
+        let step = self.chunk_size.saturating_sub(overlap).max(1);
+
+        for region in M::new(self.process.as_ref(), start, limit) {
+            if region.range.len() < value_size {
+                continue;
+            }
+
+            let mut chunks = Vec::new();
+            let mut chunk_offset = 0;
+            loop {
+                let is_last = chunk_offset + self.chunk_size >= region.range.len();
+                chunks.push((chunk_offset, is_last));
+                if is_last {
+                    break;
+                }
+                chunk_offset += step;
+            }
+
+            let process = &self.process;
+            let chunk_size = self.chunk_size;
+            let region_start = region.range.start;
+            let region_len = region.range.len();
+
+            let chunk_results: Vec<Result<Vec<usize>, ProcessError>> = chunks
+                .par_iter()
+                .map(|&(chunk_offset, is_last)| {
+                    let len = chunk_size.min(region_len - chunk_offset);
+                    let mut buffer = vec![0u8; len];
+                    match process.read_memory_slice(region_start + chunk_offset, &mut buffer) {
+                        Ok(_) => {}
+                        // A chunk that vanished or is momentarily unreadable
+                        // just gets skipped; the process disappearing aborts
+                        // the whole scan.
+                        Err(err) if err.is_process_gone() => return Err(err),
+                        Err(_) => return Ok(Vec::new()),
+                    }
+
+                    if buffer.len() < value_size {
+                        return Ok(Vec::new());
+                    }
+
+                    // Non-last chunks only report matches starting before the
+                    // overlap, since the next chunk starts exactly there and
+                    // will find anything starting at or after it, avoiding
+                    // double-counting a boundary-straddling match.
+                    let scannable_len = if is_last { buffer.len() } else { step };
+                    let mut hits = Vec::new();
+                    for local_offset in 0..=scannable_len.min(buffer.len() - value_size) {
+                        let value: T = read_value(&buffer[local_offset..local_offset + value_size]);
+                        if predicate(&value) {
+                            hits.push(region_start + chunk_offset + local_offset);
+                        }
+                    }
+
+                    Ok(hits)
+                })
+                .collect();
+
+            for result in chunk_results {
+                self.addresses.extend(result?);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn next_scan<T: Scannable, F: FnMut(&T) -> bool>(
+        &mut self,
         mut predicate: F,
-    ) {
+    ) -> Result<(), ProcessError> {
+        assert!(size_of::<T>() <= self.value_size);
+
+        let value_size = self.value_size;
+        let requests: Vec<(usize, usize)> = self
+            .addresses
+            .iter()
+            .map(|&address| (address, value_size))
+            .collect();
+        let buffers = self.process.read_memory_regions(&requests)?;
+
+        // Keep the snapshot (if any) index-aligned with the surviving
+        // addresses, refreshed with the values just read.
+        let track_snapshot = !self.snapshot.is_empty();
+        let mut kept_snapshot = Vec::new();
+
+        self.addresses = self
+            .addresses
+            .iter()
+            .zip(buffers)
+            .filter_map(|(&address, buffer)| {
+                // Region freed/unreadable since the last pass: drop it.
+                let buffer = buffer?;
+
+                let actual: T = read_value(&buffer);
+                if predicate(&actual) {
+                    if track_snapshot {
+                        kept_snapshot.extend_from_slice(&buffer);
+                    }
+                    Some(address)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        self.snapshot = kept_snapshot;
+
+        Ok(())
+    }
+
+    /// "Unknown initial value" scan: every candidate address in every region
+    /// is kept, with its current value recorded into `snapshot` so a later
+    /// `next_scan_compare` can filter by how the value moved.
+    pub fn new_scan_unknown<'a, T: Scannable, M: MemoryRegionIterator<'a, P>>(
+        &'a mut self,
+        start: usize,
+        limit: usize,
+    ) -> Result<(), ProcessError> {
         self.addresses.clear();
+        self.snapshot.clear();
         self.value_size = size_of::<T>();
 
-        for region in M::new(self.process.as_ref(), 0, usize::MAX) {
+        for region in M::new(self.process.as_ref(), start, limit) {
             let mut region_buffer = vec![0u8; region.range.len()];
             match self
                 .process
                 .read_memory_slice(region.range.start, &mut region_buffer)
             {
                 Ok(_) => {}
+                Err(err) if err.is_process_gone() => return Err(err),
                 Err(_) => continue,
             }
 
-            for offset in 0..region_buffer.len() - size_of::<T>() {
-                unsafe {
-                    let actual = std::slice::from_raw_parts(
-                        region_buffer.as_ptr().offset(offset as isize) as *const T,
-                        1,
-                    );
+            if region_buffer.len() < size_of::<T>() {
+                continue;
+            }
 
-                    if predicate(&actual[0]) {
-                        self.addresses.push(region.range.start + offset);
-                    }
-                }
+            for offset in 0..=region_buffer.len() - size_of::<T>() {
+                self.addresses.push(region.range.start + offset);
+                self.snapshot
+                    .extend_from_slice(&region_buffer[offset..offset + size_of::<T>()]);
             }
         }
+
+        Ok(())
     }
 
-    pub fn next_scan<T: PartialEq, F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
-        assert!(size_of::<T>() <= self.value_size);
+    /// Re-reads every candidate from `new_scan_unknown`/a previous
+    /// `next_scan_compare`, keeps only those satisfying `comparator` relative
+    /// to their stored snapshot value, and overwrites the snapshot with the
+    /// freshly read value so the next pass compares incrementally. Addresses
+    /// and their snapshot entries are dropped together, keeping both vectors
+    /// index-aligned; an address that becomes unreadable is dropped, not
+    /// treated as "unchanged".
+    pub fn next_scan_compare<T>(&mut self, comparator: Comparator<T>) -> Result<(), ProcessError>
+    where
+        T: Scannable + PartialOrd + Sub<Output = T>,
+    {
+        assert_eq!(size_of::<T>(), self.value_size);
 
-        self.addresses = self
+        let requests: Vec<(usize, usize)> = self
             .addresses
             .iter()
-            .filter_map(|&address| {
-                let mut buffer = vec![0u8; size_of::<T>()];
-                self.process.read_memory_slice(address, &mut buffer).ok()?;
-
-                unsafe {
-                    let actual = std::slice::from_raw_parts(buffer.as_ptr() as *const T, 1);
-
-                    return if predicate(&actual[0]) {
-                        Some(address)
-                    } else {
-                        None
-                    };
-                }
-            })
+            .map(|&address| (address, size_of::<T>()))
             .collect();
+        let buffers = self.process.read_memory_regions(&requests)?;
+
+        let mut kept_addresses = Vec::with_capacity(self.addresses.len());
+        let mut kept_snapshot = Vec::with_capacity(self.snapshot.len());
+
+        for (i, (&address, buffer)) in self.addresses.iter().zip(buffers).enumerate() {
+            // Region freed/unreadable since the last pass: drop it.
+            let Some(buffer) = buffer else { continue };
+
+            let old: T = read_value(&self.snapshot[i * size_of::<T>()..(i + 1) * size_of::<T>()]);
+            let new: T = read_value(&buffer);
+
+            let keep = match &comparator {
+                Comparator::Increased => new > old,
+                Comparator::Decreased => new < old,
+                Comparator::Changed => !new.scan_eq(&old),
+                Comparator::Unchanged => new.scan_eq(&old),
+                Comparator::IncreasedBy(delta) => new > old && (new - old).scan_eq(delta),
+                Comparator::DecreasedBy(delta) => new < old && (old - new).scan_eq(delta),
+                Comparator::Equal(value) => new.scan_eq(value),
+                Comparator::GreaterThan(value) => new > *value,
+                Comparator::LessThan(value) => new < *value,
+                Comparator::Between(low, high) => new >= *low && new <= *high,
+            };
+
+            if keep {
+                kept_addresses.push(address);
+                kept_snapshot.extend_from_slice(&buffer);
+            }
+        }
+
+        self.addresses = kept_addresses;
+        self.snapshot = kept_snapshot;
+
+        Ok(())
+    }
+
+    pub fn scan_result<'a, T: Scannable>(&'a self, offset: usize, limit: usize) -> ScanResult<'a, P, T> {
+        ScanResult::new(self, offset, limit)
     }
 
-    pub fn scan_result<'a, T: Copy>(&'a self) -> ScanResult<'a, P, T> {
-        ScanResult::new(self)
+    /// Write a single scannable value back into the process, e.g. to edit a
+    /// value a scan found. The read-modify-write counterpart is `Freezer`,
+    /// which keeps re-writing a value instead of poking it once.
+    pub fn write_value<T: Scannable>(&self, address: usize, value: T) -> Result<(), ProcessError> {
+        self.process.write_memory_slice(address, bytes_of(&value))
     }
+
+    /// Array-of-bytes signature scan: slide a window across every readable
+    /// region in `[start, start + limit)` looking for `pattern`, where
+    /// `None` entries are wildcards. Uses a Boyer-Moore-Horspool bad-character
+    /// skip table built from the pattern's trailing run of concrete bytes
+    /// (falling back to a naive byte-by-byte scan if the pattern ends in a
+    /// wildcard, since there's then no trailing run to build one from), the
+    /// same trick memory-dumper signature scanners use to stay fast on large
+    /// regions. Returns each match's absolute address together with its
+    /// matched bytes, so the caller can run post-match operations like
+    /// `rip`-relative resolution on the result.
+    pub fn pattern_scan<'a, M: MemoryRegionIterator<'a, P>>(
+        &'a mut self,
+        pattern: &[Option<u8>],
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<(usize, Vec<u8>)>, ProcessError> {
+        let pattern_len = pattern.len();
+        assert!(pattern_len > 0, "pattern must not be empty");
+        let skip_table = build_bmh_skip_table(pattern);
+
+        let mut matches = Vec::new();
+
+        for region in M::new(self.process.as_ref(), start, limit) {
+            let mut region_buffer = vec![0u8; region.range.len()];
+            match self
+                .process
+                .read_memory_slice(region.range.start, &mut region_buffer)
+            {
+                Ok(_) => {}
+                Err(err) if err.is_process_gone() => return Err(err),
+                Err(_) => continue,
+            }
+
+            if region_buffer.len() < pattern_len {
+                continue;
+            }
+
+            let mut offset = 0;
+            while offset <= region_buffer.len() - pattern_len {
+                let window = &region_buffer[offset..offset + pattern_len];
+                let is_match = window
+                    .iter()
+                    .zip(pattern.iter())
+                    .all(|(&byte, expected)| expected.map_or(true, |wanted| byte == wanted));
+
+                if is_match {
+                    matches.push((region.range.start + offset, window.to_vec()));
+                }
+
+                offset += match &skip_table {
+                    Some(table) => table[region_buffer[offset + pattern_len - 1] as usize],
+                    None => 1,
+                };
+            }
+        }
+
+        Ok(matches)
+    }
+}
+
+/// Builds the Horspool bad-character table from the trailing run of concrete
+/// (non-wildcard) bytes following `pattern`'s last wildcard, i.e. the whole
+/// pattern if it has none. Each table entry is the number of bytes it's safe
+/// to advance the scan window when the byte aligned with the pattern's last
+/// position doesn't occur (or occurs further left) in that trailing run.
+/// Returns `None` if `pattern` ends in a wildcard, since aligning on a
+/// wildcard position can't reject anything; the caller should then fall back
+/// to a naive 1-byte-at-a-time scan.
+fn build_bmh_skip_table(pattern: &[Option<u8>]) -> Option<[usize; 256]> {
+    pattern.last().copied().flatten()?;
+
+    let tail_start = pattern.iter().rposition(|byte| byte.is_none()).map_or(0, |i| i + 1);
+    let tail = &pattern[tail_start..];
+
+    let mut table = [tail.len(); 256];
+    for (i, byte) in tail[..tail.len() - 1].iter().enumerate() {
+        if let Some(byte) = byte {
+            table[*byte as usize] = tail.len() - 1 - i;
+        }
+    }
+
+    Some(table)
+}
+
+/// Parse an IDA-style signature string such as `"48 8B 3D ? ? ? ? 44 89 E3"`
+/// into a byte pattern, where `?` (or `??`) becomes a wildcard.
+pub fn parse_pattern(signature: &str) -> Vec<Option<u8>> {
+    signature
+        .split_whitespace()
+        .map(|token| {
+            if token.bytes().all(|b| b == b'?') {
+                None
+            } else {
+                Some(u8::from_str_radix(token, 16).expect("invalid signature byte"))
+            }
+        })
+        .collect()
 }
 
 pub struct ScanResult<'a, P, T>
 where
     P: Process,
-    T: Copy,
+    T: Scannable,
 {
     scanner: &'a Scanner<P>,
-    addresses_iter: Iter<'a, usize>,
+    addresses_iter: Take<Skip<Iter<'a, usize>>>,
     bytes: Vec<u8>,
     phantom: PhantomData<&'a T>,
 }
@@ -97,13 +485,15 @@ where
 impl<'a, P, T> ScanResult<'a, P, T>
 where
     P: Process,
-    T: Copy,
+    T: Scannable,
 {
-    pub fn new(scanner: &'a Scanner<P>) -> Self {
-        assert!(size_of::<T>() > scanner.value_size);
+    /// `offset`/`limit` page over the scan's candidate addresses, not over
+    /// raw memory like `MemoryRegionIterator`'s same-named params.
+    pub fn new(scanner: &'a Scanner<P>, offset: usize, limit: usize) -> Self {
+        assert!(size_of::<T>() >= scanner.value_size);
         Self {
             scanner,
-            addresses_iter: scanner.get_addresses().iter(),
+            addresses_iter: scanner.get_addresses().iter().skip(offset).take(limit),
             phantom: PhantomData,
             bytes: vec![0u8; size_of::<T>()],
         }
@@ -113,7 +503,7 @@ where
 impl<'a, P, T> Iterator for ScanResult<'a, P, T>
 where
     P: Process,
-    T: Copy,
+    T: Scannable,
 {
     type Item = (usize, T);
 
@@ -122,6 +512,47 @@ where
         self.scanner.process
             .read_memory_slice(*next, self.bytes.as_mut_slice())
             .unwrap();
-        unsafe { Some((*next, *(self.bytes.as_ptr() as *const T))) }
+        Some((*next, read_value(&self.bytes)))
+    }
+}
+
+/// Keeps a single address pinned to a value by repeatedly re-writing it on a
+/// background thread, so the target process can't make its own write stick
+/// (e.g. freezing HP). Dropping a `Freezer` stops and joins its thread, so no
+/// writer thread ever outlives the `Freezer` that owns it.
+pub struct Freezer {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Freezer {
+    /// Starts re-writing `value` to `address` in `process` every `interval`
+    /// until this `Freezer` is dropped.
+    pub fn start<P, T>(process: Arc<P>, address: usize, value: T, interval: Duration) -> Self
+    where
+        P: Process + 'static,
+        T: Scannable,
+    {
+        let bytes = bytes_of(&value).to_vec();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        let thread = thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                let _ = process.write_memory_slice(address, &bytes);
+                thread::sleep(interval);
+            }
+        });
+
+        Self { stop, thread: Some(thread) }
+    }
+}
+
+impl Drop for Freezer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
     }
 }
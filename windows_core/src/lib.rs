@@ -22,6 +22,52 @@ fn wide_chars_to_string(wide_chars: &[u16]) -> String {
         .to_string()
 }
 
+// NtQueryInformationProcess and the structures it fills in are undocumented
+// (or only loosely documented) ntdll internals not covered by the `windows`
+// crate's generated Win32 bindings, so they're declared by hand here.
+#[repr(C)]
+struct PROCESSINFOCLASS(i32);
+
+#[repr(C)]
+struct PROCESS_BASIC_INFORMATION {
+    ExitStatus: i32,
+    PebBaseAddress: *mut c_void,
+    AffinityMask: usize,
+    BasePriority: i32,
+    UniqueProcessId: usize,
+    InheritedFromUniqueProcessId: usize,
+}
+
+#[repr(C)]
+struct UNICODE_STRING {
+    Length: u16,
+    MaximumLength: u16,
+    Buffer: *mut u16,
+}
+
+#[repr(C)]
+struct PEB {
+    _reserved: [u8; 0x20],
+    ProcessParameters: *mut c_void,
+}
+
+#[repr(C)]
+struct RTL_USER_PROCESS_PARAMETERS {
+    _reserved: [u8; 0x70],
+    CommandLine: UNICODE_STRING,
+}
+
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtQueryInformationProcess(
+        process_handle: HANDLE,
+        process_information_class: PROCESSINFOCLASS,
+        process_information: *mut c_void,
+        process_information_length: u32,
+        return_length: *mut u32,
+    ) -> i32;
+}
+
 pub struct ProcessEntry {
     process_entry: PROCESSENTRY32W,
 }
@@ -136,12 +182,72 @@ impl Iterator for ModuleIterator {
     }
 }
 
+/// A snapshot of a running process good enough to pick it as a scan target,
+/// without paying to `OpenProcess` every candidate up front.
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    pub main_module_base: usize,
+}
+
 pub struct Process {
     handle: HANDLE,
     pid: u32,
 }
 
 impl Process {
+    /// Every running process, with its main module's base address, so a
+    /// caller can let the user pick a target by PID or name instead of
+    /// hardcoding one. Processes whose module list can't be snapshotted
+    /// (e.g. protected system processes) are skipped rather than aborting
+    /// the whole listing.
+    pub fn list() -> Vec<ProcessInfo> {
+        ProcessIterator::new()
+            .filter_map(|entry| {
+                // Snapshot directly rather than via `ModuleIterator`, which
+                // panics when the snapshot can't be taken.
+                let mut module_entry = MODULEENTRY32W {
+                    dwSize: size_of::<MODULEENTRY32W>() as u32,
+                    ..Default::default()
+                };
+                let found = unsafe {
+                    let handle = CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, entry.id());
+                    if handle.is_invalid() {
+                        return None;
+                    }
+                    let found = Module32FirstW(handle, &mut module_entry).as_bool();
+                    let _ = CloseHandle(handle);
+                    found
+                };
+                if !found {
+                    return None;
+                }
+                let main_module_base = module_entry.modBaseAddr as usize;
+                Some(ProcessInfo {
+                    pid: entry.id(),
+                    name: entry.name(),
+                    main_module_base,
+                })
+            })
+            .collect()
+    }
+
+    /// Attach by PID, the same as `new`; kept as a named constructor so
+    /// callers can pick between it and `open_by_name` without special-casing
+    /// `new`.
+    pub fn open_by_pid(pid: u32) -> Self {
+        Self::new(pid)
+    }
+
+    /// Attach to the first running process whose executable name matches
+    /// `name` (case-insensitively), mirroring how auto-splitting tools let
+    /// the user select a game by its `.exe` name instead of a raw PID.
+    pub fn open_by_name(name: &str) -> Option<Self> {
+        ProcessIterator::new()
+            .find(|entry| entry.name().eq_ignore_ascii_case(name))
+            .map(|entry| Self::new(entry.id()))
+    }
+
     pub fn new(pid: u32) -> Self {
         let handle;
         unsafe {
@@ -174,6 +280,90 @@ impl Process {
         wide_chars_to_string(&self.module().szModule)
     }
 
+    /// Parent PID and PEB address, via a single
+    /// `NtQueryInformationProcess(ProcessBasicInformation)` call.
+    fn basic_information(&self) -> Option<PROCESS_BASIC_INFORMATION> {
+        let mut info: PROCESS_BASIC_INFORMATION = unsafe { std::mem::zeroed() };
+        let mut return_length: u32 = 0;
+
+        let status = unsafe {
+            NtQueryInformationProcess(
+                self.handle,
+                PROCESSINFOCLASS(0), // ProcessBasicInformation
+                &mut info as *mut _ as *mut c_void,
+                size_of::<PROCESS_BASIC_INFORMATION>() as u32,
+                &mut return_length,
+            )
+        };
+
+        if status == 0 {
+            Some(info)
+        } else {
+            None
+        }
+    }
+
+    pub fn parent_pid(&self) -> Option<u32> {
+        self.basic_information()
+            .map(|info| info.InheritedFromUniqueProcessId as u32)
+    }
+
+    pub fn is_64_bit(&self) -> bool {
+        let mut wow64_info: usize = 0;
+
+        let status = unsafe {
+            NtQueryInformationProcess(
+                self.handle,
+                PROCESSINFOCLASS(26), // ProcessWow64Information
+                &mut wow64_info as *mut _ as *mut c_void,
+                size_of::<usize>() as u32,
+                null_mut(),
+            )
+        };
+
+        // A non-null WoW64 PEB address means the target is 32-bit running
+        // under WoW64; NtQueryInformationProcess failing is treated as 64-bit.
+        status != 0 || wow64_info == 0
+    }
+
+    /// Coarse run state via `GetExitCodeProcess`: `STILL_ACTIVE` means the
+    /// process is running, anything else means it has already exited.
+    pub fn status(&self) -> String {
+        let mut exit_code: u32 = 0;
+
+        let ok = unsafe { GetExitCodeProcess(self.handle, &mut exit_code).as_bool() };
+
+        if ok && exit_code == STILL_ACTIVE.0 as u32 {
+            "running".to_string()
+        } else if ok {
+            "zombie".to_string()
+        } else {
+            "unknown".to_string()
+        }
+    }
+
+    /// Reads the full command line out of the PEB's
+    /// `RTL_USER_PROCESS_PARAMETERS`, following the documented
+    /// `ProcessBasicInformation` -> `PEB.ProcessParameters` chain.
+    pub fn command_line(&self) -> Option<String> {
+        let info = self.basic_information()?;
+
+        let mut peb: PEB = unsafe { std::mem::zeroed() };
+        self.read_process_memory(info.PebBaseAddress as usize, std::slice::from_mut(&mut peb))
+            .ok()?;
+
+        let mut params: RTL_USER_PROCESS_PARAMETERS = unsafe { std::mem::zeroed() };
+        self.read_process_memory(peb.ProcessParameters as usize, std::slice::from_mut(&mut params))
+            .ok()?;
+
+        let len = (params.CommandLine.Length / 2) as usize;
+        let mut buffer = vec![0u16; len];
+        self.read_process_memory(params.CommandLine.Buffer as usize, &mut buffer)
+            .ok()?;
+
+        Some(wide_chars_to_string(&buffer))
+    }
+
     pub fn read_process_memory<T>(&self, start: usize, buffer: &mut [T]) -> Result<(), i64> {
         unsafe {
             if ReadProcessMemory(